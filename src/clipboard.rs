@@ -0,0 +1,25 @@
+use web_sys::{
+    js_sys::Reflect,
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    Clipboard,
+};
+
+/// Copies `text` to the clipboard
+///
+/// # Errors
+///
+/// Returns an error if there is no clipboard to copy to (it only exists on secure pages).
+/// The browser can still refuse the copy later on, that only gets logged to the console
+pub fn copy(text: &str) -> Result<(), &'static str> {
+    let navigator = web_sys::window().ok_or("no window")?.navigator();
+    let clipboard = Reflect::get(&navigator, &JsValue::from_str("clipboard"))
+        .ok()
+        .and_then(|c| c.dyn_into::<Clipboard>().ok())
+        .ok_or("clipboard unavailable")?;
+    let on_error = Closure::<dyn FnMut(JsValue)>::new(|e: JsValue| {
+        web_sys::console::warn_2(&"couldn't copy to the clipboard".into(), &e);
+    });
+    let _ = clipboard.write_text(text).catch(&on_error);
+    on_error.forget();
+    Ok(())
+}
@@ -1,6 +1,83 @@
-use ratzilla::ratatui::style::Color;
+use std::fmt;
 
-#[derive(Default)]
+use ratzilla::ratatui::{style::Color, widgets::BorderType};
+
+use crate::storage;
+
+// How many themes switch_colour cycles through
+pub const THEME_COUNT: usize = 5;
+
+// How many palette colours the accent rotates through
+const ACCENT_COUNT: usize = 3;
+
+// Theme names in the order switch_colour goes through them
+const THEME_NAMES: [&str; THEME_COUNT] = [
+    "Smokey Yellow",
+    "Campfire",
+    "Stag",
+    "Terminal",
+    "Campfire Light",
+];
+
+// The light / dark counterpart of each theme, by index, for toggle_pair
+const THEME_PAIRS: [Option<usize>; THEME_COUNT] = [None, Some(4), None, None, Some(1)];
+
+/// How a theme makes its entrance when it gets switched to
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum Transition {
+    // the trailing slide up every theme used to have
+    #[default]
+    Slide,
+    // a quick slide in from the left
+    Snap,
+    // a slow fade through the old background, no movement at all
+    Fade,
+}
+
+// The transition each theme comes in with, by index, None sticks with the usual slide
+const THEME_TRANSITIONS: [Option<Transition>; THEME_COUNT] = [
+    None,
+    Some(Transition::Fade),
+    Some(Transition::Snap),
+    None,
+    Some(Transition::Fade),
+];
+
+// Where a saved custom theme goes in the rotation (after all the built in ones) and what it's called
+const CUSTOM_ID: usize = THEME_COUNT;
+const CUSTOM_NAME: &str = "Custom";
+
+// localStorage key the custom theme is saved under
+static CUSTOM_THEME_KEY: &str = "custom_theme";
+
+// localStorage key for the index of the theme we're on, so it's still picked next visit
+static THEME_KEY: &str = "theme";
+
+// Labels for each palette slot, in the same order as palette()
+pub const SLOT_NAMES: [&str; 6] = [
+    "color_bg",
+    "color_fg",
+    "color_bg_alt",
+    "color_fg_alt",
+    "color_5",
+    "color_6",
+];
+
+// The custom theme and anything else off the end of the table just slides
+fn transition_for(index: usize) -> Transition {
+    THEME_TRANSITIONS
+        .get(index)
+        .copied()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Names of all the built in themes in rotation order, handy for pickers without having to cycle a theme to find them
+pub fn theme_names() -> Vec<&'static str> {
+    THEME_NAMES.to_vec()
+}
+
+#[derive(Default, Clone)]
 pub struct ColourTheme {
     pub color_bg: Color,
     pub color_fg: Color,
@@ -8,8 +85,20 @@ pub struct ColourTheme {
     pub color_fg_alt: Color,
     pub color_5: Color,
     pub color_6: Color,
+    // tint for the headshot, picked per theme so the face stands out from the block behind it
+    pub color_headshot: Color,
+    // borders for every block, so each theme has a bit of its own character
+    pub border_type: BorderType,
+    // falling characters in the empty bits of the page, only the terminal theme has this
+    pub background_rain: bool,
     pub name: String,
     id: usize,
+    // how many times the accent has been rotated through the palette, kept across theme switches
+    accent: usize,
+    // a theme made in the editor, joins the end of the rotation when there is one
+    custom: Option<[Color; 6]>,
+    // squash every theme down to just its background and foreground, for e-ink and monochrome screens
+    mono: bool,
 }
 
 #[allow(dead_code)]
@@ -22,24 +111,274 @@ impl ColourTheme {
             color_fg_alt: Color::LightBlue,
             color_5: Color::Green,
             color_6: Color::Cyan,
+            color_headshot: Color::White,
+            border_type: BorderType::Rounded,
+            background_rain: false,
             name: "Starter".to_string(),
             id: 0,
+            accent: 0,
+            custom: None,
+            mono: false,
         }
     }
 
+    /// Jump straight to the theme at `index` in the rotation
+    pub fn select(&mut self, index: usize) {
+        self.id = index % self.theme_count();
+        self.switch_colour();
+    }
+
+    /// Jump to the theme called `name` (ignoring case), returns false if there isn't one
+    pub fn select_named(&mut self, name: &str) -> bool {
+        let index = if name.eq_ignore_ascii_case(CUSTOM_NAME) && self.custom.is_some() {
+            Some(CUSTOM_ID)
+        } else {
+            THEME_NAMES
+                .iter()
+                .position(|theme| theme.eq_ignore_ascii_case(name))
+        };
+        match index {
+            Some(index) => {
+                self.select(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Make the next switch_colour land on the theme at `index` instead of carrying on in order, for jumping there with the usual transition
+    pub fn queue(&mut self, index: usize) {
+        self.id = index % self.theme_count();
+    }
+
+    /// Index of the theme we're on now, switch_colour has already moved id on to the next one
+    pub fn current(&self) -> usize {
+        (self.id + self.theme_count() - 1) % self.theme_count()
+    }
+
+    /// Swap to the light / dark counterpart of the current theme, returns false (and does nothing) if it hasn't got one
+    pub fn toggle_pair(&mut self) -> bool {
+        match THEME_PAIRS.get(self.current()).copied().flatten() {
+            Some(pair) => {
+                self.select(pair);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// How the theme we're on now comes in
+    pub fn transition(&self) -> Transition {
+        transition_for(self.current())
+    }
+
+    /// How the theme the next switch_colour lands on comes in
+    pub fn next_transition(&self) -> Transition {
+        transition_for(self.id)
+    }
+
+    /// Names of every theme in the rotation, the custom one on the end if it's been made
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names = theme_names();
+        if self.custom.is_some() {
+            names.push(CUSTOM_NAME);
+        }
+        names
+    }
+
+    /// How many themes are in the rotation, including the custom one if it's been made
+    pub fn theme_count(&self) -> usize {
+        THEME_COUNT + usize::from(self.custom.is_some())
+    }
+
     pub fn switch_colour(&mut self) {
         // shoddy coding here change later please :3
-        match self.id {
-            1 => self.to_campfire(),
-            2 => self.to_stag(),
+        match (self.id, self.custom) {
+            (1, _) => self.to_campfire(),
+            (2, _) => self.to_stag(),
+            (3, _) => self.to_terminal(),
+            (4, _) => self.to_campfire_light(),
+            (CUSTOM_ID, Some(palette)) => {
+                self.set_palette(palette);
+                self.color_headshot = self.color_fg;
+                self.border_type = BorderType::Rounded;
+                self.background_rain = false;
+                self.name = CUSTOM_NAME.to_string();
+            }
             _ => self.to_yellow(),
         }
+        for _ in 0..self.accent {
+            self.rotate_accent();
+        }
+        if self.mono {
+            self.collapse_to_mono();
+        }
         self.id += 1;
-        if self.id > 2 {
+        if self.id >= self.theme_count() {
             self.id = 0;
         }
     }
 
+    /// Turn two tone mode on or off, this takes effect from the next theme switch
+    pub fn set_mono(&mut self, mono: bool) {
+        self.mono = mono;
+    }
+
+    pub fn is_mono(&self) -> bool {
+        self.mono
+    }
+
+    // Everything that isn't the background becomes the foreground
+    fn collapse_to_mono(&mut self) {
+        self.color_bg_alt = self.color_bg;
+        self.color_fg_alt = self.color_fg;
+        self.color_5 = self.color_fg;
+        self.color_6 = self.color_fg;
+        self.color_headshot = self.color_fg;
+    }
+
+    /// Swap just the accent colour for the next of the other palette colours, leaving the background and foreground alone
+    pub fn cycle_accent(&mut self) {
+        self.rotate_accent();
+        self.accent = (self.accent + 1) % ACCENT_COUNT;
+    }
+
+    /// Which accent rotation we're on, for saving it
+    pub fn accent(&self) -> usize {
+        self.accent
+    }
+
+    /// Set the accent rotation, this takes effect from the next theme switch
+    pub fn set_accent(&mut self, accent: usize) {
+        self.accent = accent % ACCENT_COUNT;
+    }
+
+    // color_fg_alt, color_5 and color_6 take turns being the accent
+    fn rotate_accent(&mut self) {
+        (self.color_fg_alt, self.color_5, self.color_6) =
+            (self.color_5, self.color_6, self.color_fg_alt);
+    }
+
+    /// All six colours, in the same order as SLOT_NAMES
+    pub fn palette(&self) -> [Color; 6] {
+        [
+            self.color_bg,
+            self.color_fg,
+            self.color_bg_alt,
+            self.color_fg_alt,
+            self.color_5,
+            self.color_6,
+        ]
+    }
+
+    /// Each colour alongside its slot name, in the same order as palette()
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Color)> {
+        SLOT_NAMES.into_iter().zip(self.palette())
+    }
+
+    /// Overwrite all six colours at once, in the same order as palette()
+    pub fn set_palette(&mut self, palette: [Color; 6]) {
+        [
+            self.color_bg,
+            self.color_fg,
+            self.color_bg_alt,
+            self.color_fg_alt,
+            self.color_5,
+            self.color_6,
+        ] = palette;
+    }
+
+    /// The colour in palette slot `slot`, for editing one colour at a time
+    pub fn slot_mut(&mut self, slot: usize) -> Option<&mut Color> {
+        match slot {
+            0 => Some(&mut self.color_bg),
+            1 => Some(&mut self.color_fg),
+            2 => Some(&mut self.color_bg_alt),
+            3 => Some(&mut self.color_fg_alt),
+            4 => Some(&mut self.color_5),
+            5 => Some(&mut self.color_6),
+            _ => None,
+        }
+    }
+
+    /// Remember which theme we're on for next visit
+    pub fn save(&self) {
+        storage::set(THEME_KEY, &self.current().to_string());
+    }
+
+    /// The theme saved by `save` on an earlier visit, None if there isn't one or localStorage can't be read
+    pub fn load() -> Option<usize> {
+        storage::get(THEME_KEY)?.parse().ok()
+    }
+
+    /// Keep the current colours as the custom theme, saving it so it sticks around between visits
+    ///
+    /// The rotation carries on from the custom theme afterwards
+    pub fn save_custom(&mut self) {
+        storage::set(CUSTOM_THEME_KEY, &self.unrotated().export_string());
+        self.keep_custom();
+    }
+
+    // save_custom without the saving
+    fn keep_custom(&mut self) {
+        self.custom = Some(self.unrotated().palette());
+        self.name = CUSTOM_NAME.to_string();
+        self.id = (CUSTOM_ID + 1) % self.theme_count();
+    }
+
+    // These colours with the accent rotation taken back off, switch_colour puts it on again whenever the custom theme comes round
+    fn unrotated(&self) -> ColourTheme {
+        let mut base = self.clone();
+        for _ in 0..(ACCENT_COUNT - self.accent) % ACCENT_COUNT {
+            base.rotate_accent();
+        }
+        base
+    }
+
+    /// Pick up a custom theme saved on a previous visit, if there is one
+    pub fn load_custom(&mut self) {
+        self.custom = storage::get(CUSTOM_THEME_KEY)
+            .and_then(|saved| ColourTheme::import_string(&saved).ok())
+            .map(|theme| theme.palette());
+    }
+
+    /// The palette as six comma separated hex colours, short enough to paste to someone else
+    pub fn export_string(&self) -> String {
+        let hex: Vec<String> = self.palette().iter().map(|c| to_hex(*c)).collect();
+        hex.join(",")
+    }
+
+    /// Reads a palette back out of an export_string, giving a custom theme with those colours
+    ///
+    /// Everything other than the colours is how the custom theme always looks, rounded borders and no rain
+    ///
+    /// # Errors
+    ///
+    /// Fails if there aren't exactly six colours or one of them isn't a `#RRGGBB` hex colour
+    pub fn import_string(exported: &str) -> Result<ColourTheme, ParseError> {
+        let parts: Vec<&str> = exported.trim().split(',').collect();
+        let parts: [&str; 6] = parts
+            .try_into()
+            .map_err(|parts: Vec<&str>| ParseError::WrongLength(parts.len()))?;
+        let mut palette = [Color::Reset; 6];
+        for (colour, part) in palette.iter_mut().zip(parts) {
+            *colour = parse_hex(part).ok_or_else(|| ParseError::BadHex(part.trim().to_string()))?;
+        }
+        let mut theme = ColourTheme::new();
+        theme.set_palette(palette);
+        theme.color_headshot = theme.color_fg;
+        theme.border_type = BorderType::Rounded;
+        theme.background_rain = false;
+        theme.name = CUSTOM_NAME.to_string();
+        Ok(theme)
+    }
+
+    /// The current colours as `#RRGGBB` strings labelled by field name, for mirroring the palette outside the TUI
+    pub fn to_hex_map(&self) -> [(&'static str, String); 6] {
+        let palette = self.palette();
+        std::array::from_fn(|i| (SLOT_NAMES[i], to_hex(palette[i])))
+    }
+
     #[allow(clippy::wrong_self_convention)]
     pub fn to_yellow(&mut self) {
         self.color_bg = Color::from_u32(0x002E_281D);
@@ -48,7 +387,10 @@ impl ColourTheme {
         self.color_fg_alt = Color::from_u32(0x00EC_C570);
         self.color_5 = Color::from_u32(0x00C9_B077);
         self.color_6 = Color::from_u32(0x00AA_9871);
-        self.name = "Smokey Yellow".to_string();
+        self.color_headshot = Color::from_u32(0x00EC_DFC0);
+        self.border_type = BorderType::Rounded;
+        self.background_rain = false;
+        self.name = THEME_NAMES[0].to_string();
     }
 
     #[allow(clippy::wrong_self_convention)]
@@ -59,7 +401,24 @@ impl ColourTheme {
         self.color_fg_alt = Color::from_u32(0x00E6_945B);
         self.color_5 = Color::from_u32(0x0091_2D2B);
         self.color_6 = Color::from_u32(0x005C_4954);
-        self.name = "Campfire".to_string();
+        self.color_headshot = Color::from_u32(0x00B8_D6DA);
+        self.border_type = BorderType::Thick;
+        self.background_rain = false;
+        self.name = THEME_NAMES[1].to_string();
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_campfire_light(&mut self) {
+        self.color_bg = Color::from_u32(0x00EF_E8DD);
+        self.color_fg = Color::from_u32(0x0022_3A4A);
+        self.color_bg_alt = Color::from_u32(0x00C7_D6DB);
+        self.color_fg_alt = Color::from_u32(0x00B8_5A1C);
+        self.color_5 = Color::from_u32(0x0091_2D2B);
+        self.color_6 = Color::from_u32(0x005C_4954);
+        self.color_headshot = Color::from_u32(0x002A_4C66);
+        self.border_type = BorderType::Thick;
+        self.background_rain = false;
+        self.name = THEME_NAMES[4].to_string();
     }
     #[allow(clippy::wrong_self_convention)]
     pub fn to_stag(&mut self) {
@@ -69,6 +428,311 @@ impl ColourTheme {
         self.color_fg_alt = Color::from_u32(0x00CF3961);
         self.color_5 = Color::from_u32(0x008D3950);
         self.color_6 = Color::from_u32(0x007E4576);
-        self.name = "Stag".to_string();
+        self.color_headshot = Color::from_u32(0x00F0C4D0);
+        self.border_type = BorderType::Double;
+        self.background_rain = false;
+        self.name = THEME_NAMES[2].to_string();
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_terminal(&mut self) {
+        self.color_bg = Color::from_u32(0x000A_0F0A);
+        self.color_fg = Color::from_u32(0x00B8_E6B8);
+        self.color_bg_alt = Color::from_u32(0x0023_4D2A);
+        self.color_fg_alt = Color::from_u32(0x0039_FF6A);
+        self.color_5 = Color::from_u32(0x0020_B04A);
+        self.color_6 = Color::from_u32(0x007C_C98A);
+        self.color_headshot = Color::from_u32(0x00A8_FFB8);
+        self.border_type = BorderType::Plain;
+        self.background_rain = true;
+        self.name = THEME_NAMES[3].to_string();
+    }
+}
+
+/// Why a theme string couldn't be imported
+#[derive(Debug)]
+pub enum ParseError {
+    // how many colours there were instead of six
+    WrongLength(usize),
+    // the bit that wasn't a hex colour
+    BadHex(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::WrongLength(count) => write!(f, "expected 6 colours, got {count}"),
+            ParseError::BadHex(part) => write!(f, "{part:?} isn't a hex colour"),
+        }
+    }
+}
+
+/// Picks which theme to start on for a given `day` of the year, so it stays the same all day but moves on daily
+pub fn theme_for_date(day: u32, count: usize) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    day as usize % count
+}
+
+// The standard 16 ansi colours, in their usual xterm shades and index order
+const ANSI_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (128, 0, 0),
+    (0, 128, 0),
+    (128, 128, 0),
+    (0, 0, 128),
+    (128, 0, 128),
+    (0, 128, 128),
+    (192, 192, 192),
+    (128, 128, 128),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (0, 0, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+// The levels each channel steps through in the 6x6x6 part of the 256 colour palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Turns any colour into an (r, g, b) triple, so code that needs actual channel values works with named and indexed colours too
+///
+/// `Reset` has no real colour behind it so it is treated as black
+pub fn to_rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Reset | Color::Black => ANSI_RGB[0],
+        Color::Red => ANSI_RGB[1],
+        Color::Green => ANSI_RGB[2],
+        Color::Yellow => ANSI_RGB[3],
+        Color::Blue => ANSI_RGB[4],
+        Color::Magenta => ANSI_RGB[5],
+        Color::Cyan => ANSI_RGB[6],
+        Color::Gray => ANSI_RGB[7],
+        Color::DarkGray => ANSI_RGB[8],
+        Color::LightRed => ANSI_RGB[9],
+        Color::LightGreen => ANSI_RGB[10],
+        Color::LightYellow => ANSI_RGB[11],
+        Color::LightBlue => ANSI_RGB[12],
+        Color::LightMagenta => ANSI_RGB[13],
+        Color::LightCyan => ANSI_RGB[14],
+        Color::White => ANSI_RGB[15],
+        Color::Indexed(i) => indexed_to_rgb(i),
+    }
+}
+
+/// Formats any colour as a `#RRGGBB` hex string
+pub fn to_hex(color: Color) -> String {
+    let (r, g, b) = to_rgb(color);
+    format!("#{r:02X}{g:02X}{b:02X}")
+}
+
+/// Nudges one channel (0 red, 1 green, 2 blue) of a colour by `delta`, stopping at 0 and 255
+pub fn adjust_channel(colour: Color, channel: usize, delta: i16) -> Color {
+    let (r, g, b) = to_rgb(colour);
+    let mut channels = [r, g, b];
+    if let Some(c) = channels.get_mut(channel) {
+        *c = (i16::from(*c) + delta).clamp(0, 255) as u8;
+    }
+    Color::Rgb(channels[0], channels[1], channels[2])
+}
+
+/// Reads a `#RRGGBB` (or `RRGGBB`) hex string into a colour
+pub fn parse_hex(hex: &str) -> Option<Color> {
+    let hex = hex.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok().map(Color::from_u32)
+}
+
+fn indexed_to_rgb(i: u8) -> (u8, u8, u8) {
+    match i {
+        0..=15 => ANSI_RGB[i as usize],
+        16..=231 => {
+            let i = i - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let grey = 8 + (i - 232) * 10;
+            (grey, grey, grey)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_rgb_covers_every_kind_of_colour() {
+        let table = [
+            (Color::Reset, (0, 0, 0)),
+            (Color::Black, (0, 0, 0)),
+            (Color::Red, (128, 0, 0)),
+            (Color::Green, (0, 128, 0)),
+            (Color::Yellow, (128, 128, 0)),
+            (Color::Blue, (0, 0, 128)),
+            (Color::Magenta, (128, 0, 128)),
+            (Color::Cyan, (0, 128, 128)),
+            (Color::Gray, (192, 192, 192)),
+            (Color::DarkGray, (128, 128, 128)),
+            (Color::LightRed, (255, 0, 0)),
+            (Color::LightGreen, (0, 255, 0)),
+            (Color::LightYellow, (255, 255, 0)),
+            (Color::LightBlue, (0, 0, 255)),
+            (Color::LightMagenta, (255, 0, 255)),
+            (Color::LightCyan, (0, 255, 255)),
+            (Color::White, (255, 255, 255)),
+            (Color::Rgb(12, 34, 56), (12, 34, 56)),
+            // the first 16 indexes are the named colours again
+            (Color::Indexed(1), (128, 0, 0)),
+            (Color::Indexed(15), (255, 255, 255)),
+            // then the 6x6x6 cube
+            (Color::Indexed(16), (0, 0, 0)),
+            (Color::Indexed(196), (255, 0, 0)),
+            (Color::Indexed(231), (255, 255, 255)),
+            // and last the greyscale ramp
+            (Color::Indexed(232), (8, 8, 8)),
+            (Color::Indexed(255), (238, 238, 238)),
+        ];
+        for (colour, rgb) in table {
+            assert_eq!(to_rgb(colour), rgb, "{colour:?}");
+        }
+    }
+
+    #[test]
+    fn theme_for_date_is_stable_and_in_range() {
+        for count in 1..=6 {
+            for day in 0..=366 {
+                let theme = theme_for_date(day, count);
+                assert!(theme < count, "day {day} of {count} gave {theme}");
+                assert_eq!(theme, theme_for_date(day, count));
+            }
+        }
+    }
+
+    #[test]
+    fn theme_for_date_with_one_theme_always_picks_it() {
+        for day in [0, 1, 100, 365, u32::MAX] {
+            assert_eq!(theme_for_date(day, 1), 0);
+        }
+    }
+
+    #[test]
+    fn theme_for_date_moves_on_daily() {
+        assert_ne!(theme_for_date(10, 5), theme_for_date(11, 5));
+        assert_eq!(theme_for_date(0, 0), 0);
+    }
+
+    // Every built in theme, one after another
+    fn built_in_themes() -> impl Iterator<Item = ColourTheme> {
+        (0..THEME_COUNT).map(|index| {
+            let mut theme = ColourTheme::new();
+            theme.select(index);
+            theme
+        })
+    }
+
+    #[test]
+    fn to_hex_map_round_trips_through_parse_hex() {
+        for theme in built_in_themes() {
+            let map = theme.to_hex_map();
+            for ((label, hex), (slot, colour)) in map.iter().zip(theme.iter()) {
+                assert_eq!(*label, slot);
+                assert_eq!(parse_hex(hex), Some(colour), "{} {label}", theme.name);
+            }
+        }
+    }
+
+    #[test]
+    fn import_string_round_trips_every_theme() {
+        for theme in built_in_themes() {
+            let exported = theme.export_string();
+            let imported = ColourTheme::import_string(&exported).unwrap();
+            assert_eq!(imported.palette(), theme.palette(), "{}", theme.name);
+            assert_eq!(imported.export_string(), exported);
+        }
+    }
+
+    #[test]
+    fn import_string_rejects_the_wrong_number_of_colours() {
+        let five = "#000000,#111111,#222222,#333333,#444444";
+        assert!(matches!(
+            ColourTheme::import_string(five),
+            Err(ParseError::WrongLength(5))
+        ));
+        let seven = format!("{five},#555555,#666666");
+        assert!(matches!(
+            ColourTheme::import_string(&seven),
+            Err(ParseError::WrongLength(7))
+        ));
+    }
+
+    #[test]
+    fn import_string_names_the_bad_colour() {
+        let exported = "#000000,#111111,nope,#333333,#444444,#555555";
+        match ColourTheme::import_string(exported) {
+            Err(ParseError::BadHex(part)) => assert_eq!(part, "nope"),
+            Err(err) => panic!("wrong error: {err}"),
+            Ok(_) => panic!("imported a palette with a bad colour in it"),
+        }
+    }
+
+    #[test]
+    fn theme_names_match_the_rotation() {
+        let names = theme_names();
+        assert_eq!(names.len(), THEME_COUNT);
+        for (name, theme) in names.iter().zip(built_in_themes()) {
+            assert_eq!(*name, theme.name);
+        }
+    }
+
+    #[test]
+    fn names_include_the_custom_theme_once_there_is_one() {
+        let mut theme = ColourTheme::new();
+        assert_eq!(theme.names(), theme_names());
+        theme.custom = Some(theme.palette());
+        let names = theme.names();
+        assert_eq!(names.len(), theme.theme_count());
+        assert_eq!(names.last(), Some(&CUSTOM_NAME));
+    }
+
+    #[test]
+    fn imported_theme_looks_like_the_custom_theme() {
+        let mut terminal = ColourTheme::new();
+        terminal.select(3);
+        assert!(terminal.background_rain);
+        let imported = ColourTheme::import_string(&terminal.export_string()).unwrap();
+
+        let mut custom = ColourTheme::new();
+        custom.custom = Some(imported.palette());
+        custom.select(CUSTOM_ID);
+        assert_eq!(imported.name, custom.name);
+        assert_eq!(imported.border_type, custom.border_type);
+        assert_eq!(imported.background_rain, custom.background_rain);
+        assert_eq!(imported.color_headshot, custom.color_headshot);
+    }
+
+    #[test]
+    fn custom_theme_comes_back_the_same_with_the_accent_rotated() {
+        for accent in 0..ACCENT_COUNT {
+            let mut theme = ColourTheme::new();
+            theme.set_accent(accent);
+            theme.select(1);
+            theme.keep_custom();
+            let saved = theme.palette();
+
+            theme.select(0);
+            theme.select(CUSTOM_ID);
+            assert_eq!(theme.palette(), saved, "accent {accent}");
+        }
     }
 }
@@ -0,0 +1,31 @@
+use web_sys::js_sys;
+
+// Where the contact form sends to
+pub const CONTACT_EMAIL: &str = "woonters@gmail.com";
+
+/// A `mailto:` url for `to` with the subject and body filled in, empty fields are left off so the mail client doesn't show blank ones
+pub fn mailto_url(to: &str, subject: &str, body: &str) -> String {
+    let params: Vec<String> = [("subject", subject), ("body", body)]
+        .into_iter()
+        .filter(|(_, value)| !value.trim().is_empty())
+        .map(|(key, value)| format!("{key}={}", js_sys::encode_uri_component(value)))
+        .collect();
+    if params.is_empty() {
+        format!("mailto:{to}")
+    } else {
+        format!("mailto:{to}?{}", params.join("&"))
+    }
+}
+
+/// Hands a composed email over to the visitor's mail client
+///
+/// # Errors
+///
+/// Returns an error if there is no window to send the page to the `mailto:` url from
+pub fn open_mailto(subject: &str, body: &str) -> Result<(), &'static str> {
+    web_sys::window()
+        .ok_or("no window")?
+        .location()
+        .set_href(&mailto_url(CONTACT_EMAIL, subject, body))
+        .map_err(|_| "the browser wouldn't open it")
+}
@@ -0,0 +1,77 @@
+use std::{
+    cell::OnceCell,
+    sync::{Arc, Mutex},
+};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{App, Tabs};
+
+// The app, once main has made it, so the functions js calls have something to drive
+thread_local! {
+    static APP: OnceCell<Arc<Mutex<App>>> = const { OnceCell::new() };
+}
+
+/// Hands the app over so the exported functions can reach it, only the first call does anything
+pub fn register(app: &Arc<Mutex<App>>) {
+    APP.with(|cell| {
+        let _ = cell.set(Arc::clone(app));
+    });
+}
+
+// Runs `f` on the app, false if it isn't registered yet or is poisoned / busy drawing
+fn with_app(f: impl FnOnce(&mut App) -> bool) -> bool {
+    APP.with(|cell| {
+        let Some(app) = cell.get() else {
+            return false;
+        };
+        let Ok(mut app) = app.try_lock() else {
+            return false;
+        };
+        f(&mut app)
+    })
+}
+
+/// Switch straight to the theme called `name`, e.g. `set_theme("Campfire")`
+///
+/// Returns false if there's no theme by that name, a transition is still playing or the app couldn't be reached
+#[wasm_bindgen]
+pub fn set_theme(name: &str) -> bool {
+    with_app(|app| {
+        // a switch still on its way out would land on the theme after this one, so leave it be until it's done
+        if !app.transitions_complete() || !app.theme.borrow_mut().select_named(name) {
+            return false;
+        }
+        app.theme.borrow().save();
+        app.update_meta();
+        app.update_page_colours();
+        app.dirty = true;
+        true
+    })
+}
+
+/// Move on to the next theme with the usual transition, same as pressing W
+///
+/// Returns false if a transition was still playing so the switch got dropped, or the app couldn't be reached
+#[wasm_bindgen]
+pub fn next_theme() -> bool {
+    with_app(|app| {
+        app.dirty = true;
+        app.cycle_colour()
+    })
+}
+
+/// Show the tab called `name`, returns false for tabs that don't exist
+///
+/// If a transition is still playing the tab shows once it's done, the same as pressing Tab would
+#[wasm_bindgen]
+pub fn goto_tab(name: &str) -> bool {
+    with_app(|app| {
+        let Some(tab) = Tabs::from_name(name) else {
+            return false;
+        };
+        app.switch_tab(tab);
+        app.dirty = true;
+        true
+    })
+}
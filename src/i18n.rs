@@ -0,0 +1,244 @@
+/// Languages the page chrome can be shown in, picked with `?lang=`
+///
+/// Only the titles, key hints and blurbs are translated, the about text is still English
+#[derive(Copy, Clone, Default)]
+pub enum Lang {
+    #[default]
+    English,
+    French,
+}
+
+impl Lang {
+    /// Turns a language code like `fr` into a language, anything we don't have falls back to English
+    pub fn from_code(code: &str) -> Lang {
+        match code.to_ascii_lowercase().as_str() {
+            "fr" | "fr-fr" => Lang::French,
+            _ => Lang::English,
+        }
+    }
+
+    /// All the ui strings in this language
+    pub fn labels(self) -> &'static Labels {
+        match self {
+            Lang::English => &ENGLISH,
+            Lang::French => &FRENCH,
+        }
+    }
+}
+
+// How many entries there are in the help bar
+const INSTRUCTION_COUNT: usize = 26;
+
+/// Every bit of ui copy in one language, block titles and all
+pub struct Labels {
+    pub title: &'static str,
+    pub mini_about: &'static str,
+    pub about: &'static str,
+    pub links: &'static str,
+    pub navigation: &'static str,
+    pub headshot: &'static str,
+    // what the headshot shows, for screen readers as the canvas itself says nothing
+    pub headshot_alt: &'static str,
+    pub theme_editor: &'static str,
+    pub onboarding: &'static str,
+    pub mini_about_text: &'static str,
+    pub onboarding_hint: &'static str,
+    pub touch: &'static str,
+    pub theme_editor_hint: &'static str,
+    pub too_small: &'static str,
+    pub posts: &'static str,
+    pub no_posts: &'static str,
+    pub contact: &'static str,
+    pub contact_subject: &'static str,
+    pub contact_message: &'static str,
+    pub contact_hint: &'static str,
+    pub theme_sheet: &'static str,
+    pub theme_sheet_hint: &'static str,
+    pub share_qr: &'static str,
+    pub share_qr_hint: &'static str,
+    pub import_prompt: &'static str,
+    // status line messages, the ones ending in _failed get ": why" added on the end
+    pub qr_failed: &'static str,
+    pub copied_page: &'static str,
+    pub copy_failed: &'static str,
+    pub copied_theme: &'static str,
+    pub imported_theme: &'static str,
+    pub import_failed: &'static str,
+    // followed by the file name
+    pub screenshot_saved: &'static str,
+    pub screenshot_failed: &'static str,
+    pub screenshot_no_canvas: &'static str,
+    pub contact_empty: &'static str,
+    pub contact_sent: &'static str,
+    pub contact_failed: &'static str,
+    pub no_theme_pair: &'static str,
+    pub motion_reduced: &'static str,
+    pub motion_on: &'static str,
+    pub effects_paused: &'static str,
+    pub effects_on: &'static str,
+    // thanks for staying a while, one for each of the time on site milestones
+    pub milestones: [&'static str; 3],
+    // names of the tabs, in the same order as the Tabs enum
+    pub tabs: [&'static str; 2],
+    // each entry in the help bar as (what it does, the key for it)
+    pub instructions: [(&'static str, &'static str); INSTRUCTION_COUNT],
+}
+
+static ENGLISH: Labels = Labels {
+    title: "WhoamI?",
+    mini_about: "WhatAmI??",
+    about: "About",
+    links: "Links",
+    navigation: "Navigation",
+    headshot: "hey! that's me",
+    headshot_alt: "Portrait of Jemma",
+    theme_editor: "Theme Editor",
+    onboarding: "press any key",
+    mini_about_text: "Hi, I'm Jemma (She / Her), come look at my silly things :p",
+    onboarding_hint: "New here? It's all keyboard driven, the controls are down here ↓",
+    touch: "controls",
+    theme_editor_hint: " <j/k> slot <tab> channel <h/l> adjust <enter> save <esc> cancel ",
+    too_small: "window too small, please make it bigger",
+    posts: "Posts",
+    no_posts: "No posts yet",
+    contact: "Say Hi",
+    contact_subject: "Subject",
+    contact_message: "Message",
+    contact_hint: " <tab> next field <ctrl+enter> send <esc> cancel ",
+    theme_sheet: "Themes",
+    theme_sheet_hint: " <esc> back ",
+    share_qr: " scan me ",
+    share_qr_hint: " <esc> close ",
+    import_prompt: "Paste a theme",
+    qr_failed: "couldn't make a qr code for this page",
+    copied_page: "copied page text",
+    copy_failed: "couldn't copy",
+    copied_theme: "copied theme",
+    imported_theme: "imported theme",
+    import_failed: "couldn't import",
+    screenshot_saved: "saved",
+    screenshot_failed: "couldn't save screenshot",
+    screenshot_no_canvas: "screenshots need the canvas, they don't work with backend=dom",
+    contact_empty: "write something first",
+    contact_sent: "opening your mail app",
+    contact_failed: "couldn't open mail",
+    no_theme_pair: "this theme has no light / dark pair",
+    motion_reduced: "motion reduced",
+    motion_on: "motion on",
+    effects_paused: "effects paused",
+    effects_on: "effects on",
+    milestones: [
+        "thanks for sticking around!",
+        "still here? you're the best",
+        "ok you've basically moved in now",
+    ],
+    tabs: ["Main", "Blog"],
+    instructions: [
+        (" Switch Tab ", "<tab/h/l>"),
+        (" Pick Tab ", "<n then h/l enter>"),
+        (" Switch colour theme ", "<W>"),
+        (" Switch Accent ", "<a>"),
+        (" Light / Dark ", "<L>"),
+        (" Jump To Theme ", "<alt+1-9>"),
+        (" Theme Editor ", "<E>"),
+        (" Export / Import Theme ", "<ctrl+e/i>"),
+        (" Copy Page ", "<Y>"),
+        (" Share QR ", "<Q>"),
+        (" Contact Me ", "<m>"),
+        (" Save Screenshot ", "<ctrl+p>"),
+        (" Next List Item ", "<j>"),
+        (" Previous List Item", "<k>"),
+        (" Select List Item ", "<enter>"),
+        (" Top Of List ", "<gg>"),
+        (" Scroll Post ", "<J/K pgup/pgdn>"),
+        (" More Links ", "<←/→>"),
+        (" Open Link ", "<1-9>"),
+        (" Toggle Headshot Colour ", "<c>"),
+        (" Invert Headshot ", "<i>"),
+        (" Posterise Headshot ", "<q>"),
+        (" Zoom Headshot ", "<+/-/0>"),
+        (" Swap Sides ", "<s>"),
+        (" Pause Effects ", "<space>"),
+        (" Reduce Motion ", "<R>"),
+    ],
+};
+
+static FRENCH: Labels = Labels {
+    title: "QuiSuisJe?",
+    mini_about: "QueSuisJe??",
+    about: "À propos",
+    links: "Liens",
+    navigation: "Navigation",
+    headshot: "coucou ! c'est moi",
+    headshot_alt: "Portrait de Jemma",
+    theme_editor: "Éditeur de thème",
+    onboarding: "appuie sur une touche",
+    mini_about_text: "Salut, moi c'est Jemma (elle), viens voir mes petits trucs :p",
+    onboarding_hint: "Nouveau ici ? Tout se fait au clavier, les commandes sont juste là ↓",
+    touch: "commandes",
+    theme_editor_hint:
+        " <j/k> couleur <tab> canal <h/l> ajuster <enter> enregistrer <esc> annuler ",
+    too_small: "fenêtre trop petite, agrandis-la s'il te plaît",
+    posts: "Articles",
+    no_posts: "Pas encore d'articles",
+    contact: "Dis bonjour",
+    contact_subject: "Objet",
+    contact_message: "Message",
+    contact_hint: " <tab> champ suivant <ctrl+enter> envoyer <esc> annuler ",
+    theme_sheet: "Thèmes",
+    theme_sheet_hint: " <esc> retour ",
+    share_qr: " scanne-moi ",
+    share_qr_hint: " <esc> fermer ",
+    import_prompt: "Colle un thème",
+    qr_failed: "impossible de créer un code qr pour cette page",
+    copied_page: "texte de la page copié",
+    copy_failed: "copie impossible",
+    copied_theme: "thème copié",
+    imported_theme: "thème importé",
+    import_failed: "import impossible",
+    screenshot_saved: "enregistré :",
+    screenshot_failed: "capture d'écran impossible",
+    screenshot_no_canvas: "les captures ont besoin du canevas, pas possible avec backend=dom",
+    contact_empty: "écris quelque chose d'abord",
+    contact_sent: "ouverture de ton appli mail",
+    contact_failed: "impossible d'ouvrir le mail",
+    no_theme_pair: "ce thème n'a pas de version claire / sombre",
+    motion_reduced: "animations réduites",
+    motion_on: "animations activées",
+    effects_paused: "effets en pause",
+    effects_on: "effets activés",
+    milestones: [
+        "merci de rester un peu !",
+        "toujours là ? t'es génial·e",
+        "ok, t'as carrément emménagé maintenant",
+    ],
+    tabs: ["Accueil", "Blog"],
+    instructions: [
+        (" Changer d'onglet ", "<tab/h/l>"),
+        (" Choisir l'onglet ", "<n puis h/l entrée>"),
+        (" Changer de thème ", "<W>"),
+        (" Changer d'accent ", "<a>"),
+        (" Clair / Sombre ", "<L>"),
+        (" Aller au thème ", "<alt+1-9>"),
+        (" Éditeur de thème ", "<E>"),
+        (" Exporter / Importer le thème ", "<ctrl+e/i>"),
+        (" Copier la page ", "<Y>"),
+        (" Partager en QR ", "<Q>"),
+        (" Me contacter ", "<m>"),
+        (" Capture d'écran ", "<ctrl+p>"),
+        (" Élément suivant ", "<j>"),
+        (" Élément précédent ", "<k>"),
+        (" Ouvrir l'élément ", "<enter>"),
+        (" Haut de la liste ", "<gg>"),
+        (" Défiler l'article ", "<J/K pgup/pgdn>"),
+        (" Autres liens ", "<←/→>"),
+        (" Ouvrir le lien ", "<1-9>"),
+        (" Couleur du portrait ", "<c>"),
+        (" Inverser le portrait ", "<i>"),
+        (" Postériser le portrait ", "<q>"),
+        (" Zoom du portrait ", "<+/-/0>"),
+        (" Changer de côté ", "<s>"),
+        (" Pause des effets ", "<espace>"),
+        (" Réduire les animations ", "<R>"),
+    ],
+};
@@ -1,50 +1,54 @@
+// The slides get a faint trail by running a slightly longer fade alongside them,
+// so content right behind the moving edge is still dim and catches up a moment later
 #[macro_export]
-macro_rules! slide_in_and_out {
-    ($t:expr,$c:expr) => {{
-        fx::sequence(&[
-            fx::prolong_start(
-                $t,
-                fx::slide_out(
-                    Motion::DownToUp,
-                    10,
-                    1,
-                    $c,
-                    EffectTimer::from_ms(500, Interpolation::Linear),
-                ),
-            ),
+macro_rules! trailing_slide_in {
+    ($d:expr,$c:expr) => {{
+        fx::parallel(&[
             fx::slide_in(
                 Motion::DownToUp,
-                10,
+                $d,
                 1,
                 $c,
                 EffectTimer::from_ms(500, Interpolation::Linear),
             ),
+            fx::fade_from_fg($c, EffectTimer::from_ms(700, Interpolation::QuadOut)),
         ])
     }};
 }
 
 #[macro_export]
-macro_rules! slide_in_and_out_disp {
-    ($t:expr,$c:expr,$s:expr,$e_start:expr) => {{
-        fx::sequence(&[
-            fx::prolong_start(
-                $t,
-                fx::slide_out(
-                    Motion::DownToUp,
-                    10,
-                    1,
-                    $c,
-                    EffectTimer::from_ms(500, Interpolation::Linear),
-                ),
-            ),
-            fx::dispatch_event($s, $e_start),
-            fx::slide_in(
+macro_rules! trailing_slide_out {
+    ($d:expr,$c:expr) => {{
+        fx::parallel(&[
+            fx::slide_out(
                 Motion::DownToUp,
-                10,
+                $d,
                 1,
                 $c,
                 EffectTimer::from_ms(500, Interpolation::Linear),
             ),
+            fx::fade_to_fg($c, EffectTimer::from_ms(400, Interpolation::QuadIn)),
+        ])
+    }};
+}
+
+#[macro_export]
+macro_rules! slide_in_and_out {
+    ($t:expr,$c:expr,$d:expr,$tr:expr) => {{
+        fx::sequence(&[
+            fx::prolong_start($t, $crate::transition_out($tr, $d, $c)),
+            $crate::transition_in($tr, $d, $c),
+        ])
+    }};
+}
+
+#[macro_export]
+macro_rules! slide_in_and_out_disp {
+    ($t:expr,$c:expr,$d:expr,$tr:expr,$s:expr,$e_start:expr) => {{
+        fx::sequence(&[
+            fx::prolong_start($t, $crate::transition_out($tr, $d, $c)),
+            $crate::dispatch_colour_event($s, $e_start),
+            $crate::transition_in($tr, $d, $c),
             // fx::dispatch_event($s, $e_end),
         ])
     }};
@@ -1,35 +1,44 @@
-use color_eyre::owo_colors::OwoColorize;
-use image::{DynamicImage, GenericImageView, ImageReader};
+#[cfg(feature = "image")]
+use image::{DynamicImage, ImageReader, RgbImage};
+use qrcode::QrCode;
+#[cfg(feature = "image")]
+use ratzilla::ratatui::widgets::{
+    canvas::{Canvas, Shape},
+    Widget,
+};
+#[cfg(feature = "image")]
+use std::io::Cursor;
 use std::{
     cell::RefCell,
-    io::{self, Bytes, Cursor},
+    io,
     sync::{
+        atomic::{AtomicUsize, Ordering},
         mpsc::{self, Receiver, Sender},
-        Arc, Mutex,
+        Arc, Mutex, MutexGuard,
     },
 };
 use tachyonfx::Duration;
-use web_sys::HtmlImageElement;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use ratzilla::{
     ratatui::{
+        backend::Backend,
+        buffer::Buffer,
         layout::{
             Alignment, Constraint,
             Direction::{self, Horizontal},
-            Layout,
+            Layout, Margin, Position, Rect,
         },
         style::{Color, Modifier, Style, Stylize},
-        text::{self, Line, Span, ToLine},
-        widgets::{
-            canvas::{Canvas, Context, Map, MapResolution, Shape},
-            Block, BorderType, List, ListState, Paragraph, Widget, Wrap,
-        },
+        text::{self, Line, Span, Text},
+        widgets::{Block, Clear, List, ListItem, ListState, Paragraph, Wrap},
         Frame, Terminal,
     },
     utils::call_js_function,
+    web_sys::js_sys,
 };
 use tachyonfx::{
-    fx, Effect, EffectRenderer, EffectTimer, Interpolation, Motion, Shader, SimpleRng,
+    fx, CellFilter, Effect, EffectRenderer, EffectTimer, Interpolation, Motion, Shader, SimpleRng,
 };
 
 use ratzilla::{
@@ -40,9 +49,23 @@ use ratzilla::{
     WebRenderer,
 };
 
+mod clipboard;
 mod colors;
+mod contact;
+mod exports;
+mod i18n;
 mod macros;
-use colors::ColourTheme;
+mod media;
+mod meta;
+mod params;
+mod rain;
+mod screenshot;
+mod spinner;
+mod storage;
+mod touch;
+mod visibility;
+use colors::{ColourTheme, Transition, SLOT_NAMES};
+use i18n::{Labels, Lang};
 
 // TODO: Include a few more of these for different screen sizes
 // This is used later on as *banner art*
@@ -54,8 +77,87 @@ static TITLE_ART: &str = r"
 ╚███╔███╔╚██████╔╚██████╔██║ ╚████║  ██║  █████████║  █████████║
  ╚══╝╚══╝ ╚═════╝ ╚═════╝╚═╝  ╚═══╝  ╚═╝  ╚══════╚═╝  ╚═╚══════╝";
 
+// Everything shown in the links list, the index in here is the index in the list
+static LINKS: [Link; 3] = [
+    Link {
+        label: "Github",
+        url: "https://github.com/woonters",
+        icon: "⎇",
+        description: "My open-source projects and general code tinkering",
+    },
+    Link {
+        label: "Youtube",
+        url: "https://youtube.com/@woonters",
+        icon: "▶",
+        description: "Animations, music and whatever else I've been making",
+    },
+    Link {
+        label: "Twitter",
+        url: "https://twitter.com/woonters",
+        icon: "✦",
+        description: "Random thoughts, works in progress and the odd shitpost",
+    },
+];
+
+// The less important links, shown as one compact row under the main list
+//
+// these carry on the numbering from LINKS, so the first one here is opened with the key after the last main link
+static EXTRA_LINKS: [Link; 2] = [
+    Link {
+        label: "source",
+        url: "https://github.com/woonters/website_v2",
+        icon: "{}",
+        description: "How this site is put together, it's all Rust",
+    },
+    Link {
+        label: "bugs",
+        url: "https://github.com/woonters/website_v2/issues",
+        icon: "!",
+        description: "Spotted something broken? Let me know here",
+    },
+];
+
+// What goes between each link in the compact row
+const EXTRA_LINK_SEPARATOR: &str = " · ";
+
+// Most blank lines `?spacing=` can put between list items
+const MAX_LIST_SPACING: u16 = 3;
+
+// How many columns we keep free for the icon so the labels all line up
+const LINK_ICON_WIDTH: usize = 2;
+
+// localStorage key that gets set once someone has seen the first visit hint
+static ONBOARDED_KEY: &str = "onboarded";
+
+// localStorage key for which accent colour was picked
+static ACCENT_KEY: &str = "accent";
+
+// localStorage key for whether the main layout is mirrored
+static MIRRORED_KEY: &str = "mirrored";
+
+// localStorage key for reduced motion being switched on or off by hand, it wins over the system setting
+static REDUCED_MOTION_KEY: &str = "reduced_motion";
+
+#[cfg(feature = "image")]
 static HEADSHOT: &[u8; 883046] = include_bytes!("../static/smallest.png");
 
+// id of the hidden element the headshot description goes in
+static HEADSHOT_ALT_ID: &str = "headshot-alt";
+
+// Stand in for the headshot when we're built without image support
+#[cfg(not(feature = "image"))]
+static HEADSHOT_ART: &str = r"
+    .-~~~~~-.
+   /  _   _  \
+  |  (o) (o)  |
+  |     ^     |
+  |   \___/   |
+   \         /
+    '-.___.-'
+   __/     \__
+  /           \
+ /  woonters   \";
+
 /// Entry point for code, setup stuff and pass it off to ratzilla functions.
 ///
 /// # Panics
@@ -68,33 +170,212 @@ static HEADSHOT: &[u8; 883046] = include_bytes!("../static/smallest.png");
 ///
 /// This function will return an error if backend / terminal initialisation fails
 fn main() -> io::Result<()> {
-    // We should have a DOM backend version for accessibility
-    let backend = CanvasBackend::new()?;
-    let terminal = Terminal::new(backend)?;
+    install_panic_hook();
 
     // Note sure why Arc is suggesting Mutex<App> isn't Send + Sync ( clippy even suggests wrapping it in a Mutex!)
     // We do this so the on_key_event and draw_web functions can both capture and mutate the app when needed
+    #[allow(clippy::arc_with_non_send_sync)]
     let state: Arc<Mutex<App>> = Arc::new(Mutex::new(App::default()));
     // This channel handles sending messages for when to change colour, this allows us to hide the colour change in the middle of an animation making it much smoother
     let (tx, rx) = mpsc::channel();
     {
-        let mut mod_state = state.lock().unwrap();
-        mod_state.theme.borrow_mut().switch_colour(); // quickly switch colours at the start so we are on the first theme
-        mod_state.main_state_animations.tx = Some(tx);
+        let mut mod_state = lock_or_recover(&state, "app");
+        if let Some(accent) = storage::get(ACCENT_KEY).and_then(|a| a.parse().ok()) {
+            mod_state.theme.borrow_mut().set_accent(accent);
+        }
+        mod_state.theme.borrow_mut().load_custom();
+        // needs to be set before the first switch so the starting theme gets squashed down too
+        let mono = matches!(params::get("mono").as_deref(), Some("1" | "true"));
+        mod_state.theme.borrow_mut().set_mono(mono);
+        let stored_theme = ColourTheme::load();
+        if params::get("theme").as_deref() == Some("daily") {
+            let theme = colors::theme_for_date(day_of_year(), colors::THEME_COUNT);
+            mod_state.theme.borrow_mut().select(theme);
+        } else if let Some(theme) = stored_theme {
+            mod_state.theme.borrow_mut().select(theme);
+        } else {
+            mod_state.theme.borrow_mut().switch_colour(); // quickly switch colours at the start so we are on the first theme
+        }
+        let transition = mod_state.theme.borrow().transition();
+        mod_state.main_state_animations = MainAnimationState::entrance(transition);
+        mod_state.main_state_animations.tx = Some(tx.clone());
+        mod_state.blog_state_animations = BlogAnimationState::entrance(transition);
+        mod_state.blog_state_animations.tx = Some(tx);
+        mod_state.intro_playing = true;
         mod_state.rx = Some(rx);
+        mod_state.tab = Tabs::starting(params::get("tab").as_deref());
+        mod_state.highlight_tab(mod_state.tab);
+        mod_state.update_meta();
+        mod_state.update_page_colours();
+        mod_state.dom_backend = params::get("backend").as_deref() == Some("dom");
+        // taps get mapped to cells using the canvas's cell size, the dom backend has no canvas to map them against
+        mod_state.touch = media::is_touch_device() && !mod_state.dom_backend;
+        // the onboarding hint waits for a key press, which might never come on a touch screen
+        mod_state.show_onboarding = storage::get(ONBOARDED_KEY).is_none() && !mod_state.touch;
+        mod_state.mirrored = storage::get(MIRRORED_KEY).as_deref() == Some("true");
+        mod_state.reduced_motion = match storage::get(REDUCED_MOTION_KEY) {
+            Some(stored) => stored == "true",
+            None => media::matches("(prefers-reduced-motion: reduce)"),
+        };
+        mod_state.last_input = js_sys::Date::now();
+        mod_state.session_start = mod_state.last_input;
+        mod_state.fixed_grid = params::get("layout").as_deref() == Some("fixed");
+        let max_fps = params::get("fps")
+            .and_then(|fps| fps.parse::<u32>().ok())
+            .filter(|fps| *fps > 0)
+            .unwrap_or(DEFAULT_MAX_FPS);
+        mod_state.min_frame_ms = 1000.0 / f64::from(max_fps);
+        mod_state.list_spacing = params::get("spacing")
+            .and_then(|spacing| spacing.parse::<u16>().ok())
+            .map_or(0, |spacing| spacing.min(MAX_LIST_SPACING));
+        mod_state.scroll_step = params::get("scroll")
+            .and_then(|step| step.parse::<u16>().ok())
+            .filter(|step| *step > 0)
+            .map_or(DEFAULT_SCROLL_STEP, |step| step.min(MAX_SCROLL_STEP));
+        mod_state.lang = params::get("lang")
+            .map(|code| Lang::from_code(&code))
+            .unwrap_or_default();
+        mod_state.update_headshot_alt();
+        #[cfg(feature = "image")]
+        {
+            mod_state.headshot_image = DecodedImage::decode(HEADSHOT);
+        }
+    }
+
+    exports::register(&state);
+
+    if lock_or_recover(&state, "app").touch {
+        let tap_state = Arc::clone(&state);
+        touch::on_tap(move |column, row| {
+            lock_or_recover(&tap_state, "app").handle_tap(column, row);
+        });
+    }
+
+    let visibility_state = Arc::clone(&state);
+    visibility::on_return(move |away_ms| {
+        lock_or_recover(&visibility_state, "app").resume(away_ms);
+    });
+
+    // `?backend=dom` draws real text into the page instead of a canvas, so screen readers have something to read
+    let dom_backend = lock_or_recover(&state, "app").dom_backend;
+    if dom_backend {
+        run(Terminal::new(DomBackend::new()?)?, &state);
+    } else {
+        let backend = CanvasBackend::new()?;
+        // the canvas backend always draws a 16px font into 10x19 cells and has no way to change that,
+        // so on high dpi screens all we can do for now is leave a note for whoever is wondering why it looks small
+        let pixel_ratio = media::device_pixel_ratio();
+        if pixel_ratio > 1.0 {
+            web_sys::console::info_1(
+                &format!("devicePixelRatio is {pixel_ratio}, the canvas draws at a fixed font size so text may look small").into(),
+            );
+        }
+        run(Terminal::new(backend)?, &state);
     }
 
-    let event_state = Arc::clone(&state);
+    Ok(())
+}
+
+/// Hooks the app up to `terminal`'s key events and draw loop, whichever backend it's using
+fn run<B: Backend + 'static>(terminal: Terminal<B>, state: &Arc<Mutex<App>>) {
+    let event_state = Arc::clone(state);
     terminal.on_key_event(move |key_event| {
-        event_state.lock().unwrap().handle_events(&key_event);
+        lock_or_recover(&event_state, "app").handle_events(&key_event);
     });
 
-    let render_state = Arc::clone(&state);
+    let render_state = Arc::clone(state);
     terminal.draw_web(move |frame| {
-        render_state.lock().unwrap().render(frame);
+        lock_or_recover(&render_state, "app").render(frame);
     });
+}
 
-    Ok(())
+/// Swaps the dead canvas out for a themed "something broke" message whenever we panic
+///
+/// The actual panic message goes to the console so it can still be debugged
+fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        web_sys::console::error_1(&info.to_string().into());
+
+        // don't trust any of the app state at this point, just grab a fresh copy of the first theme
+        let mut theme = ColourTheme::new();
+        theme.to_yellow();
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+            return;
+        };
+        let (Some(body), Ok(message)) = (document.body(), document.create_element("pre")) else {
+            return;
+        };
+        message.set_text_content(Some("something broke — press reload"));
+        let _ = message.set_attribute(
+            "style",
+            &format!("color: {}; padding: 2em;", theme.color_fg_alt),
+        );
+        let _ = body.set_attribute("style", &format!("background-color: {};", theme.color_bg));
+        body.set_inner_html("");
+        let _ = body.append_child(&message);
+    }));
+}
+
+/// One line error message, in the accent colours flipped round so it stands out from normal status messages without clashing with the theme
+fn error_banner(message: &str, theme: &ColourTheme) -> Paragraph<'static> {
+    Paragraph::new(format!(" ! {message} "))
+        .fg(theme.color_bg)
+        .bg(theme.color_fg_alt)
+        .bold()
+        .right_aligned()
+}
+
+/// Where the error banner sits, over the right hand end of the help bar's bottom border where status messages go
+fn error_banner_area(message: &str, help_bar: Rect) -> Rect {
+    // the " ! " and trailing space around the message
+    let width = (display_width(message) as u16 + 4).min(help_bar.width.saturating_sub(2));
+    Rect::new(
+        help_bar.right().saturating_sub(width + 1),
+        help_bar.bottom().saturating_sub(1),
+        width,
+        1,
+    )
+}
+
+/// A qr code for the page exactly as it is, query params and all, so it opens the same on a phone
+fn page_qr() -> Option<QrCode> {
+    let url = web_sys::window()?.location().href().ok()?;
+    QrCode::new(url).ok()
+}
+
+/// Takes the lock even if something panicked while holding it, logging a warning instead of panicking again
+///
+/// The list states are just a selected index and offset, so whatever was left in there is still fine to use
+fn lock_or_recover<'a, T>(mutex: &'a Mutex<T>, name: &str) -> MutexGuard<'a, T> {
+    let (guard, poisoned) = lock_even_if_poisoned(mutex);
+    if poisoned {
+        web_sys::console::warn_1(
+            &format!("{name} was poisoned, carrying on with it anyway").into(),
+        );
+    }
+    guard
+}
+
+/// Takes the lock whether or not it's poisoned, along with whether it was
+fn lock_even_if_poisoned<T>(mutex: &Mutex<T>) -> (MutexGuard<'_, T>, bool) {
+    match mutex.lock() {
+        Ok(guard) => (guard, false),
+        Err(poisoned) => (poisoned.into_inner(), true),
+    }
+}
+
+/// Milliseconds since the page loaded, falls back to the wall clock if there's no performance api
+fn performance_now() -> f64 {
+    web_sys::window()
+        .and_then(|w| w.performance())
+        .map_or_else(js_sys::Date::now, |p| p.now())
+}
+
+/// How many days into the year it is for the visitor, starting at 0 on new years day
+fn day_of_year() -> u32 {
+    let now = js_sys::Date::new_0();
+    let new_year = js_sys::Date::new_with_year_month_day(now.get_full_year(), 0, 1);
+    ((now.get_time() - new_year.get_time()) / 86_400_000.0) as u32
 }
 
 /// App is the general struct which holds all the state / data about the site
@@ -105,23 +386,352 @@ struct App {
     theme: RefCell<ColourTheme>,
     tab: Tabs,
     tabs_state: Arc<Mutex<ListState>>,
+    // a tab asked for while a transition was still playing, waiting to go once it's done
+    pending_tab: TabQueue,
+    // the nav bar has focus, moving round it only highlights a tab and Enter is what goes there
+    nav_focused: bool,
     main_state: MainState,
     main_state_animations: MainAnimationState,
+    blog_state: BlogState,
+    blog_state_animations: BlogAnimationState,
     rng: SimpleRng,
     rx: Option<Receiver<ColourEvent>>,
+    headshot_colour: ColourType,
+    headshot_inverted: bool,
+    #[cfg(feature = "image")]
+    headshot_zoom: Zoom,
+    // how many grey levels the headshot is posterised down to, None for smooth
+    #[cfg(feature = "image")]
+    headshot_levels: Option<u8>,
+    // the headshot png decoded, done once at startup as it's far too slow to do while drawing
+    #[cfg(feature = "image")]
+    headshot_image: DecodedImage,
+    // stop at the ends of lists rather than wrapping round to the other end
+    clamp_lists: bool,
+    // headshot on the left and text on the right
+    mirrored: bool,
+    // first time visitors get a hint pointing at the controls until they press something
+    show_onboarding: bool,
+    // short message shown in the corner of the help bar, e.g. confirming something got copied
+    status: Option<Status>,
+    // set whenever something changes that means the next frame has to actually be drawn
+    dirty: bool,
+    // what got drawn last, reused as is while nothing needs redrawing
+    last_frame: Option<Buffer>,
+    // the theme editor overlay, when it's open
+    theme_editor: Option<ThemeEditor>,
+    // first key of a chord, waiting to see if the second one follows
+    pending_key: ChordBuffer,
+    // the contact form overlay, when it's open
+    contact_form: Option<ContactForm>,
+    // set when someone wants the running animations to jump straight to the end
+    skip_animations: bool,
+    // the entrance from loading the page is still going, moving around the page during it skips to the end
+    intro_playing: bool,
+    // the area of the last frame we drew, so animations started from key presses know how big the screen is
+    last_area: Rect,
+    // when the visitor last pressed anything, in ms since the epoch
+    last_input: f64,
+    // a slow breathing on the title that plays while nobody is touching anything
+    idle_effect: Option<Effect>,
+    // the visitor asked their os for less motion, so leave out anything purely decorative
+    reduced_motion: bool,
+    // lay the main screen out in a fixed size grid instead of reflowing it to fit
+    fixed_grid: bool,
+    // which language the titles and key hints are in
+    lang: Lang,
+    // on a touch screen with nothing to type on, so draw some buttons to tap instead
+    touch: bool,
+    // where each touch button was last drawn and the key it stands in for
+    touch_buttons: Vec<(Rect, KeyCode)>,
+    // drawing with `?backend=dom`, so there's no canvas to tap on or take a screenshot of
+    dom_backend: bool,
+    // shortest gap between redraws when nothing is animating, from the fps cap
+    min_frame_ms: f64,
+    // when we last actually drew a frame, from performance.now()
+    last_render: f64,
+    // blank lines left under each list item, so lists aren't so cramped on big screens
+    list_spacing: u16,
+    // how many lines a single scroll of the open post moves it by
+    scroll_step: u16,
+    // design view with every theme's palette side by side, drawn instead of the current tab
+    show_theme_sheet: bool,
+    // effects switched off for now, anything that would animate jumps straight to how it ends
+    effects_paused: bool,
+    // falling characters behind the empty block, for themes that have it
+    rain: rain::Rain,
+    // when the page was opened, in ms since the epoch
+    session_start: f64,
+    // how many of the time on site milestones have had their message
+    milestones_shown: usize,
+    // qr code of the page url, shown over everything while it's open
+    share_qr: Option<QrCode>,
+}
+
+// Plain copy of the interesting bits of App, used for dumping the state as json
+#[cfg(feature = "debug")]
+#[derive(serde::Serialize)]
+struct StateSnapshot {
+    tab: &'static str,
+    theme: String,
+    accent: usize,
+    selected_link: Option<usize>,
+    selected_tab: Option<usize>,
+    headshot_full_colour: bool,
+    headshot_inverted: bool,
+    #[cfg(feature = "image")]
+    headshot_zoom: f64,
+    mirrored: bool,
+    clamp_lists: bool,
+    show_onboarding: bool,
+    animating: bool,
+    dropped_colour_events: usize,
+}
+
+// Keys that can start a chord, these wait a moment for a second key before doing their own thing
+//
+// none of them should have a binding of their own in handle_key, it would only go off after the wait
+const CHORD_STARTS: [char; 1] = ['g'];
+// How long a chord's first key waits for the second before it counts as a press on its own
+const CHORD_TIMEOUT_MS: f64 = 400.0;
+
+// What a chord does once both keys are in
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Chord {
+    // gg, back to the top of the list
+    Top,
+}
+
+// What became of a key press once the chord buffer has seen it
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ChordPress {
+    // the first key of a chord, held until the second comes in
+    Held,
+    // the second key finished off a chord
+    Done(Chord),
+    // the held key wasn't the start of a chord after all, it needs pressing on its own before this one carries on
+    Broken(char),
+    // nothing to do with chords
+    Plain,
+}
+
+// First key of a chord and when it was pressed, waiting to see if the second one follows
+#[derive(Default)]
+struct ChordBuffer {
+    pending: Option<(char, f64)>,
+}
+
+impl ChordBuffer {
+    fn press(&mut self, c: char, now: f64) -> ChordPress {
+        if let Some((first, _)) = self.pending.take() {
+            return match (first, c) {
+                ('g', 'g') => ChordPress::Done(Chord::Top),
+                _ => ChordPress::Broken(first),
+            };
+        }
+        if CHORD_STARTS.contains(&c) {
+            self.pending = Some((c, now));
+            return ChordPress::Held;
+        }
+        ChordPress::Plain
+    }
+
+    /// The held key, handed back straight away because something that can't finish the chord came in
+    fn flush(&mut self) -> Option<char> {
+        self.pending.take().map(|(key, _)| key)
+    }
+
+    /// The held key, once it has waited too long for a second one and counts as a press on its own
+    fn expire(&mut self, now: f64) -> Option<char> {
+        self.pending
+            .take_if(|(_, pressed)| now - *pressed >= CHORD_TIMEOUT_MS)
+            .map(|(key, _)| key)
+    }
+}
+
+// Smallest screen the main layout still makes sense on, anything under this just gets asked to be bigger
+const MIN_MAIN_WIDTH: u16 = 20;
+const MIN_MAIN_HEIGHT: u16 = 10;
+
+// Widest the main screen gets before it stops stretching and sits in the middle instead
+const MAX_CONTENT_WIDTH: u16 = 160;
+
+// The grid the main screen is laid out in with `?layout=fixed`, so it looks the same on every screen big enough to hold it
+//
+// cells are a fixed size in the canvas so we can't scale this up, bigger screens just get more background round the edges
+const FIXED_GRID_WIDTH: u16 = 120;
+const FIXED_GRID_HEIGHT: u16 = 40;
+
+// The buttons on the touch pad in reading order, with the key each one presses
+static TOUCH_BUTTONS: [(&str, KeyCode); 4] = [
+    ("▲", KeyCode::Up),
+    ("▼", KeyCode::Down),
+    ("⏎", KeyCode::Enter),
+    ("◐", KeyCode::Char('W')),
+];
+const TOUCH_PAD_WIDTH: u16 = 16;
+const TOUCH_PAD_HEIGHT: u16 = 8;
+
+// How many light modules go round the qr code, the spec asks for four so scanners can find the edges
+const QR_QUIET_ZONE: usize = 4;
+
+// Size of each tile in the theme sheet, wide enough for six three cell swatches and the border
+const THEME_TILE_WIDTH: u16 = 24;
+const THEME_TILE_HEIGHT: u16 = 3;
+
+// How long status messages stay up for
+const STATUS_DURATION_MS: f64 = 3000.0;
+
+// How long someone has had the page open before we thank them for it, what we say is in Labels::milestones
+const TIME_ON_SITE_MILESTONES: [f64; 3] = [3.0 * 60_000.0, 10.0 * 60_000.0, 30.0 * 60_000.0];
+
+// How much a status message matters, errors get picked out so they aren't mistaken for confirmations
+#[derive(Clone, Copy, PartialEq)]
+enum Severity {
+    Info,
+    Error,
+}
+
+struct Status {
+    message: String,
+    severity: Severity,
+    // Date::now() time it goes away at
+    until: f64,
+}
+
+// How much each h/l press in the theme editor moves a channel by
+const THEME_EDITOR_STEP: i16 = 8;
+
+// What's selected in the theme editor, edits happen straight on the live theme so the whole page previews them
+struct ThemeEditor {
+    slot: usize,
+    // 0 red, 1 green, 2 blue
+    channel: usize,
+    // the theme from before any edits, put back if they get thrown away
+    original: ColourTheme,
+}
+
+// What's been typed into the contact form so far
+#[derive(Default)]
+struct ContactForm {
+    subject: String,
+    message: String,
+    // which field typing goes into, false for the subject and true for the message
+    on_message: bool,
 }
 
 // Enum for storing what tab we are looking at
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Default, PartialEq)]
 enum Tabs {
+    #[default]
     Main,
     Blog,
 }
 
-impl Default for Tabs {
-    fn default() -> Self {
-        Self::Main
+impl Tabs {
+    // Every tab, in the same order as the nav bar
+    const ALL: [Tabs; 2] = [Tabs::Main, Tabs::Blog];
+
+    // Where this tab is in the nav bar
+    fn index(self) -> usize {
+        match self {
+            Tabs::Main => 0,
+            Tabs::Blog => 1,
+        }
     }
+
+    /// The tab `forward` (or back) from this one, wrapping round at either end
+    fn step(self, forward: bool) -> Tabs {
+        let count = Tabs::ALL.len();
+        let next = if forward {
+            self.index() + 1
+        } else {
+            self.index() + count - 1
+        };
+        Tabs::ALL[next % count]
+    }
+
+    /// The tab called `name`, for picking one from outside like `?tab=` or js
+    fn from_name(name: &str) -> Option<Tabs> {
+        match name.to_ascii_lowercase().as_str() {
+            "main" => Some(Tabs::Main),
+            "blog" => Some(Tabs::Blog),
+            _ => None,
+        }
+    }
+
+    /// The tab to open on from a `?tab=` value, Main when there isn't one or it's not a tab we have
+    fn starting(param: Option<&str>) -> Tabs {
+        param.and_then(Tabs::from_name).unwrap_or_default()
+    }
+
+    // What goes in the browser tab / link previews while we are on this tab
+    fn meta(self) -> (&'static str, &'static str) {
+        match self {
+            Tabs::Main => ("Woonters", "Hi, I'm Jemma, come look at my silly things :p"),
+            Tabs::Blog => (
+                "Woonters - Blog",
+                "Jemma's blog, things I've made and things I've learnt",
+            ),
+        }
+    }
+}
+
+// Holds on to a tab switch that came in mid transition, the last one asked for is the one that happens
+#[derive(Default)]
+struct TabQueue {
+    pending: Option<Tabs>,
+}
+
+impl TabQueue {
+    /// Ask for `tab`, handing it straight back to switch to if the transitions are done or holding on to it otherwise
+    fn request(&mut self, tab: Tabs, transitions_complete: bool) -> Option<Tabs> {
+        if transitions_complete {
+            self.pending = None;
+            Some(tab)
+        } else {
+            self.pending = Some(tab);
+            None
+        }
+    }
+
+    /// The held tab, once the transitions it was waiting on have finished
+    fn settle(&mut self, transitions_complete: bool) -> Option<Tabs> {
+        self.pending.take_if(|_| transitions_complete)
+    }
+
+    /// Where we're headed, so stepping through tabs carries on from the last press rather than the tab on screen
+    fn latest(&self, current: Tabs) -> Tabs {
+        self.pending.unwrap_or(current)
+    }
+}
+
+// How many colour switches were sent after the receiver had gone, these used to vanish without a trace
+static DROPPED_COLOUR_EVENTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Sends `event` as an effect in a sequence, like fx::dispatch_event but failed sends get counted and logged rather than ignored
+fn dispatch_colour_event(sender: Sender<ColourEvent>, event: ColourEvent) -> Effect {
+    fx::run_once(fx::effect_fn_buf(Some(event), 0, move |event, _, _| {
+        if let Some(event) = event.take() {
+            if sender.send(event).is_err() {
+                let dropped = DROPPED_COLOUR_EVENTS.fetch_add(1, Ordering::Relaxed) + 1;
+                web_sys::console::warn_1(
+                    &format!("colour switch dropped, nothing is listening ({dropped} so far)")
+                        .into(),
+                );
+            }
+        }
+    }))
+}
+
+/// Applies every colour switch waiting in `rx` to `theme`, returning how many there were
+fn drain_colour_switches(rx: &Receiver<ColourEvent>, theme: &mut ColourTheme) -> usize {
+    let mut switches = 0;
+    while rx.try_recv().is_ok() {
+        theme.switch_colour();
+        switches += 1;
+    }
+    switches
 }
 
 // Enum for sending when we want to switch colour schemes
@@ -130,10 +740,89 @@ enum ColourEvent {
     Switch,
 }
 
+// A single entry in the links list
+struct Link {
+    label: &'static str,
+    url: &'static str,
+    icon: &'static str,
+    // a short blurb about where the link goes, shown while it's selected
+    description: &'static str,
+}
+
+/// The url for the link at `index`, counting through the links list and then the compact row, or `None` if there isn't one there
+fn link_url(index: usize) -> Option<&'static str> {
+    LINKS
+        .iter()
+        .chain(&EXTRA_LINKS)
+        .nth(index)
+        .map(|link| link.url)
+}
+
 // Storing any state data from the main page
 #[derive(Default)]
 struct MainState {
     links_state: Arc<Mutex<ListState>>,
+    // which link in the compact row is selected, only one of this and the list has a selection at a time
+    extra_link: Option<usize>,
+}
+
+// A blog post, the title goes in the post list and the body in the reading pane
+struct Post {
+    title: &'static str,
+    body: &'static str,
+}
+
+// Title and description for the browser tab / link previews, an open post puts its own title in place of the tab's
+fn page_meta(tab: Tabs, post: Option<&Post>) -> (String, &'static str) {
+    let (title, description) = tab.meta();
+    match post {
+        Some(post) => (format!("{} - Woonters", post.title), description),
+        None => (title.to_string(), description),
+    }
+}
+
+// Every post on the blog, newest first
+static POSTS: [Post; 0] = [];
+
+// Storing any state data from the blog page
+#[derive(Default)]
+struct BlogState {
+    posts_state: Arc<Mutex<ListState>>,
+    // how many lines down the open post has been scrolled
+    scroll: u16,
+    // how many lines of the open post fit on screen, as of the last frame
+    viewport: u16,
+}
+
+// How many lines J / K move the open post by, `?scroll=` changes it
+const DEFAULT_SCROLL_STEP: u16 = 3;
+const MAX_SCROLL_STEP: u16 = 20;
+
+// How far page up / page down move through `viewport` lines of text, one less so the last line stays on screen for context
+fn page_step(viewport: u16) -> u16 {
+    viewport.saturating_sub(1).max(1)
+}
+
+// How far animations move on each frame, and how far to move them when skipping straight to the end
+const FRAME_TICK_MS: u32 = 7;
+const SKIP_TICK_MS: u32 = 60_000;
+
+// Cap on how often the page redraws while nothing is animating, `?fps=` changes it
+const DEFAULT_MAX_FPS: u32 = 60;
+
+// How long the page sits untouched before the idle animation starts, and how long each half breath of it takes
+const IDLE_AFTER_MS: f64 = 30_000.0;
+const IDLE_BREATH_MS: u32 = 4_000;
+// The breathing never ends, so rather than holding the page at full frame rate it gets a frame this often
+const IDLE_FRAME_MS: f64 = 100.0;
+
+// Bounds on how far the slides reach, so they don't look tiny on big screens or swallow small ones
+const MIN_SLIDE_DISTANCE: u16 = 4;
+const MAX_SLIDE_DISTANCE: u16 = 24;
+
+/// How far the slide animations should reach for a screen `height` cells tall
+fn slide_distance(height: u16) -> u16 {
+    (height / 4).clamp(MIN_SLIDE_DISTANCE, MAX_SLIDE_DISTANCE)
 }
 
 // Storing Effect data for all the animations on the main screen
@@ -149,108 +838,402 @@ struct MainAnimationState {
 }
 
 impl MainAnimationState {
+    fn effects(&self) -> [&Effect; 7] {
+        [
+            &self.tabs_effect,
+            &self.title_effect,
+            &self.mini_about_effect,
+            &self.links_effect,
+            &self.about_effect,
+            &self.headshot_effect,
+            &self.help_effect,
+        ]
+    }
+
+    /// True while any pane still has an effect running
+    fn any_running(&self) -> bool {
+        self.effects().iter().any(|effect| effect.running())
+    }
+
+    /// True once every pane has finished animating, not just the one that switches the colours
+    fn all_complete(&self) -> bool {
+        !self.any_running()
+    }
+
     /// Whenever we switch colour themes we want to slide out the old colours to a neutral background, then slide the new theme in
     /// One animation needs to trigger sending a message to tx
     /// the rest have slightly random offsets to make it all a little less uniform
-    fn create_fresh_animations(&mut self, bg_1: Color, rng: &mut SimpleRng) {
+    /// The `transition` is how the theme being switched to comes in, the old one goes out the same way
+    fn create_fresh_animations(
+        &mut self,
+        bg_1: Color,
+        rng: &mut SimpleRng,
+        area: Rect,
+        transition: Transition,
+    ) {
+        let distance = slide_distance(area.height);
         self.title_effect = slide_in_and_out_disp!(
             0,
             bg_1,
+            distance,
+            transition,
             self.tx.as_ref().unwrap().clone(),
             ColourEvent::Switch
         );
-        self.mini_about_effect = slide_in_and_out!(rng.gen() % 100, bg_1);
-        self.links_effect = slide_in_and_out!(rng.gen() % 100, bg_1);
-        self.about_effect = slide_in_and_out!(rng.gen() % 100, bg_1);
-        self.headshot_effect = slide_in_and_out!(rng.gen() % 100, bg_1);
-        self.tabs_effect = slide_in_and_out!(rng.gen() % 100, bg_1);
-        self.help_effect = slide_in_and_out!(rng.gen() % 100, bg_1);
+        self.mini_about_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+        self.links_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+        self.about_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+        self.headshot_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+        self.tabs_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+        self.help_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+    }
+
+    /// Initial animations for all of the cells, coming in the way the starting theme likes to
+    fn entrance(transition: Transition) -> Self {
+        Self::entrance_over(
+            transition,
+            slide_distance(ratzilla::utils::get_window_size().height),
+        )
+    }
+
+    /// The entrance, sliding `distance` cells
+    fn entrance_over(transition: Transition, distance: u16) -> Self {
+        let colour = Color::from_u32(0x0010_1010);
+        MainAnimationState {
+            tabs_effect: transition_in(transition, distance, colour),
+            title_effect: transition_in(transition, distance, colour),
+            mini_about_effect: fx::prolong_start(100, transition_in(transition, distance, colour)),
+            links_effect: fx::prolong_start(150, transition_in(transition, distance, colour)),
+            about_effect: fx::prolong_start(90, transition_in(transition, distance, colour)),
+            headshot_effect: fx::prolong_start(20, transition_in(transition, distance, colour)),
+            help_effect: transition_in(transition, distance, colour),
+            tx: Option::None,
+        }
     }
 }
 
 impl Default for MainAnimationState {
     /// Initial slide in animations for all of the cells
     fn default() -> Self {
-        MainAnimationState {
-            tabs_effect: fx::slide_in(
-                Motion::DownToUp,
-                10,
-                1,
-                Color::from_u32(0x0010_1010),
-                EffectTimer::from_ms(500, Interpolation::Linear),
-            ),
-            title_effect: fx::slide_in(
-                Motion::DownToUp,
-                10,
-                1,
-                Color::from_u32(0x0010_1010),
-                EffectTimer::from_ms(500, Interpolation::Linear),
-            ),
-            mini_about_effect: fx::prolong_start(
-                100,
-                fx::slide_in(
-                    Motion::DownToUp,
-                    10,
-                    1,
-                    Color::from_u32(0x0010_1010),
-                    EffectTimer::from_ms(500, Interpolation::Linear),
-                ),
-            ),
-            links_effect: fx::prolong_start(
-                150,
-                fx::slide_in(
-                    Motion::DownToUp,
-                    10,
-                    1,
-                    Color::from_u32(0x0010_1010),
-                    EffectTimer::from_ms(500, Interpolation::Linear),
-                ),
-            ),
-            about_effect: fx::prolong_start(
-                90,
-                fx::slide_in(
-                    Motion::DownToUp,
-                    10,
-                    1,
-                    Color::from_u32(0x0010_1010),
-                    EffectTimer::from_ms(500, Interpolation::Linear),
-                ),
-            ),
-            headshot_effect: fx::prolong_start(
-                20,
-                fx::slide_in(
-                    Motion::DownToUp,
-                    10,
-                    1,
-                    Color::from_u32(0x0010_1010),
-                    EffectTimer::from_ms(500, Interpolation::Linear),
-                ),
-            ),
-            help_effect: fx::slide_in(
-                Motion::DownToUp,
-                10,
-                1,
-                Color::from_u32(0x0010_1010),
-                EffectTimer::from_ms(500, Interpolation::Linear),
-            ),
+        MainAnimationState::entrance(Transition::default())
+    }
+}
+
+// Storing Effect data for all the animations on the blog, same idea as MainAnimationState
+struct BlogAnimationState {
+    tabs_effect: Effect,
+    posts_effect: Effect,
+    content_effect: Effect,
+    help_effect: Effect,
+    tx: Option<Sender<ColourEvent>>,
+}
+
+impl BlogAnimationState {
+    fn effects(&self) -> [&Effect; 4] {
+        [
+            &self.tabs_effect,
+            &self.posts_effect,
+            &self.content_effect,
+            &self.help_effect,
+        ]
+    }
+
+    /// True while any pane still has an effect running
+    fn any_running(&self) -> bool {
+        self.effects().iter().any(|effect| effect.running())
+    }
+
+    /// True once every pane has finished animating
+    fn all_complete(&self) -> bool {
+        !self.any_running()
+    }
+
+    /// Same as the main screen, the post list is the one that sends the colour switch
+    fn create_fresh_animations(
+        &mut self,
+        bg_1: Color,
+        rng: &mut SimpleRng,
+        area: Rect,
+        transition: Transition,
+    ) {
+        let distance = slide_distance(area.height);
+        self.posts_effect = slide_in_and_out_disp!(
+            0,
+            bg_1,
+            distance,
+            transition,
+            self.tx.as_ref().unwrap().clone(),
+            ColourEvent::Switch
+        );
+        self.content_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+        self.tabs_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+        self.help_effect = slide_in_and_out!(rng.gen() % 100, bg_1, distance, transition);
+    }
+
+    /// Initial animations for the blog, played the first time it's shown
+    fn entrance(transition: Transition) -> Self {
+        let distance = slide_distance(ratzilla::utils::get_window_size().height);
+        let colour = Color::from_u32(0x0010_1010);
+        BlogAnimationState {
+            tabs_effect: transition_in(transition, distance, colour),
+            posts_effect: fx::prolong_start(60, transition_in(transition, distance, colour)),
+            content_effect: fx::prolong_start(120, transition_in(transition, distance, colour)),
+            help_effect: transition_in(transition, distance, colour),
             tx: Option::None,
         }
     }
 }
 
+impl Default for BlogAnimationState {
+    fn default() -> Self {
+        BlogAnimationState::entrance(Transition::default())
+    }
+}
+
+/// A pane coming in `distance` cells from `colour`, in the style of `transition`
+fn transition_in(transition: Transition, distance: u16, colour: Color) -> Effect {
+    match transition {
+        Transition::Slide => trailing_slide_in!(distance, colour),
+        Transition::Snap => fx::slide_in(
+            Motion::LeftToRight,
+            distance,
+            1,
+            colour,
+            EffectTimer::from_ms(250, Interpolation::QuartOut),
+        ),
+        Transition::Fade => fx::fade_from(
+            colour,
+            colour,
+            EffectTimer::from_ms(900, Interpolation::SineInOut),
+        ),
+    }
+}
+
+/// A pane going out to `colour`, the other half of `transition_in`
+fn transition_out(transition: Transition, distance: u16, colour: Color) -> Effect {
+    match transition {
+        Transition::Slide => trailing_slide_out!(distance, colour),
+        Transition::Snap => fx::slide_out(
+            Motion::LeftToRight,
+            distance,
+            1,
+            colour,
+            EffectTimer::from_ms(200, Interpolation::QuartIn),
+        ),
+        Transition::Fade => fx::fade_to(
+            colour,
+            colour,
+            EffectTimer::from_ms(700, Interpolation::SineInOut),
+        ),
+    }
+}
+
 impl App {
     // What we do each frame, here we want to
     fn render(&mut self, frame: &mut Frame) {
-        if self.rx.as_ref().unwrap().try_recv().is_ok() {
-            self.theme.borrow_mut().switch_colour();
+        if frame.area() != self.last_area {
+            self.dirty = true;
+        }
+        self.last_area = frame.area();
+        if self
+            .status
+            .as_ref()
+            .is_some_and(|status| js_sys::Date::now() > status.until)
+        {
+            self.status = None;
+            self.dirty = true;
+        }
+        // apply every switch that's waiting, if they only came out one a frame the theme could end up behind the animations
+        let switches =
+            drain_colour_switches(self.rx.as_ref().unwrap(), &mut self.theme.borrow_mut());
+        if switches > 0 {
+            if switches > 1 {
+                web_sys::console::warn_1(
+                    &format!("{switches} colour switches queued up in one frame").into(),
+                );
+            }
+            self.theme.borrow().save();
+            self.update_page_colours();
+            self.dirty = true;
+        }
+        self.check_time_on_site();
+        self.expire_pending_key(performance_now());
+        if self.intro_playing && self.transitions_complete() {
+            self.intro_playing = false;
         }
+        if let Some(tab) = self.pending_tab.settle(self.transitions_complete()) {
+            self.switch_tab(tab);
+        }
+        // the idle breathing is on the title art, which only the main screen has
+        if self.idle_effect.is_none()
+            && matches!(self.tab, Tabs::Main)
+            && !self.reduced_motion
+            && !self.effects_paused
+            && self.transitions_complete()
+            && js_sys::Date::now() - self.last_input > IDLE_AFTER_MS
+        {
+            self.idle_effect = Some(self.gen_idle_effect());
+        }
+
+        // when nothing has changed just hand back what we drew last time, the backend sees no difference and skips drawing
+        let now = performance_now();
+        if self.idle_effect.is_some() && now - self.last_render >= IDLE_FRAME_MS {
+            self.dirty = true;
+        }
+        if !self.needs_redraw(now) {
+            if let Some(last_frame) = &self.last_frame {
+                frame.buffer_mut().clone_from(last_frame);
+                return;
+            }
+        }
+
+        if self.show_theme_sheet {
+            self.render_theme_sheet(frame);
+        } else {
+            match self.tab {
+                Tabs::Main => self.render_main(frame),
+                Tabs::Blog => self.render_blog(frame),
+            }
+        }
+        if let Some(editor) = &self.theme_editor {
+            self.render_theme_editor(frame, editor);
+        }
+        if let Some(form) = &self.contact_form {
+            self.render_contact_form(frame, form);
+        }
+        if let Some(code) = &self.share_qr {
+            self.render_share_qr(frame, code);
+        }
+        #[cfg(feature = "debug")]
+        self.render_busy_dot(frame);
+        self.last_frame = Some(frame.buffer_mut().clone());
+        self.last_render = now;
+        self.dirty = false;
+    }
+
+    // Anything still moving has to be drawn every frame, otherwise only redraw when something changed and we're not over the fps cap
+    fn needs_redraw(&self, now: f64) -> bool {
+        self.animating() || (self.dirty && now - self.last_render >= self.min_frame_ms)
+    }
+
+    // Whether the tab we're on has finished its transitions, theme switches wait on this so they don't stack up
+    fn transitions_complete(&self) -> bool {
         match self.tab {
-            Tabs::Main => self.render_main(frame),
-            Tabs::Blog => todo!(),
+            Tabs::Main => self.main_state_animations.all_complete(),
+            Tabs::Blog => self.blog_state_animations.all_complete(),
+        }
+    }
+
+    // Whether anything is part way through moving, these step along once per frame so they always get drawn
+    fn animating(&self) -> bool {
+        #[cfg(feature = "image")]
+        let zooming = !self.headshot_zoom.settled();
+        #[cfg(not(feature = "image"))]
+        let zooming = false;
+        // the idle breathing isn't in here, it's slow enough to get by on the odd frame from render
+        zooming || self.rain_active() || !self.transitions_complete()
+    }
+
+    // The rain is just for fun, so it stays off whenever motion has been turned down
+    fn rain_active(&self) -> bool {
+        self.theme.borrow().background_rain && !self.reduced_motion && !self.effects_paused
+    }
+
+    // Overlay listing each palette colour with its channels, the selected channel is picked out in reverse
+    fn render_theme_editor(&self, frame: &mut Frame<'_>, editor: &ThemeEditor) {
+        let theme = self.theme.borrow();
+        let lines: Vec<Line> = theme
+            .iter()
+            .enumerate()
+            .map(|(slot, (name, colour))| {
+                let (r, g, b) = colors::to_rgb(colour);
+                let mut spans = vec![
+                    Span::styled("  ", Style::default().bg(colour)),
+                    Span::raw(format!(" {name:<12} {} ", colors::to_hex(colour))),
+                ];
+                for (channel, (label, value)) in
+                    [("R", r), ("G", g), ("B", b)].into_iter().enumerate()
+                {
+                    let span = Span::raw(format!(" {label} {value:>3}"));
+                    if slot == editor.slot && channel == editor.channel {
+                        spans.push(span.reversed());
+                    } else {
+                        spans.push(span);
+                    }
+                }
+                let line = Line::from(spans);
+                if slot == editor.slot {
+                    line.style(self.gen_highlight_style())
+                } else {
+                    line
+                }
+            })
+            .collect();
+        let editor_block = Block::bordered()
+            .border_type(theme.border_type)
+            .title(self.labels().theme_editor)
+            .title_alignment(Alignment::Center)
+            .title_bottom(self.labels().theme_editor_hint)
+            .fg(theme.color_fg_alt);
+        let editor_widget = Paragraph::new(lines)
+            .block(editor_block)
+            .fg(theme.color_fg)
+            .bg(theme.color_bg);
+        let area = centred_rect(frame.area(), 66, SLOT_NAMES.len() as u16 + 2);
+        frame.render_widget(Clear, area);
+        frame.render_widget(editor_widget, area);
+    }
+
+    // Overlay with a one line subject box over a bigger message box, the one being typed in gets a cursor and the highlight colour
+    fn render_contact_form(&self, frame: &mut Frame<'_>, form: &ContactForm) {
+        let theme = self.theme.borrow();
+        let area = centred_rect(frame.area(), 60, 14);
+        let form_block = Block::bordered()
+            .border_type(theme.border_type)
+            .title(self.labels().contact)
+            .title_alignment(Alignment::Center)
+            .title_bottom(self.labels().contact_hint)
+            .fg(theme.color_fg_alt)
+            .bg(theme.color_bg);
+        let fields = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(3), Constraint::Fill(1)])
+            .split(form_block.inner(area));
+        frame.render_widget(Clear, area);
+        frame.render_widget(form_block, area);
+        for (i, (title, text)) in [
+            (self.labels().contact_subject, &form.subject),
+            (self.labels().contact_message, &form.message),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let active = form.on_message == (i == 1);
+            let field_block = Block::bordered()
+                .border_type(theme.border_type)
+                .title(title)
+                .fg(if active {
+                    theme.color_fg_alt
+                } else {
+                    theme.color_fg
+                });
+            let text = if active {
+                format!("{text}▏")
+            } else {
+                text.clone()
+            };
+            let field = Paragraph::new(text)
+                .block(field_block)
+                .fg(theme.color_fg)
+                .wrap(Wrap { trim: false });
+            frame.render_widget(field, fields[i]);
         }
     }
 
     fn render_main(&mut self, frame: &mut Frame<'_>) {
+        let Some(area) = self.page_area(frame) else {
+            return;
+        };
         let o_total_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints(vec![
@@ -258,15 +1241,33 @@ impl App {
                 Constraint::Fill(2),
                 Constraint::Max(2),
             ])
-            .split(frame.area());
+            .split(area);
+        // when mirrored the text column and the headshot column swap sides, so pick which half is which up front
+        let (text_col, side_col) = if self.mirrored { (1, 0) } else { (0, 1) };
+        let mut o0_constraints = vec![Constraint::Percentage(60), Constraint::Percentage(40)];
+        let mut o4_constraints = vec![Constraint::Fill(1), Constraint::Max(40)];
+        if self.mirrored {
+            o0_constraints.reverse();
+            o4_constraints.reverse();
+        }
         let o0_layout = Layout::default()
             .direction(Horizontal)
-            .constraints(vec![Constraint::Percentage(60), Constraint::Percentage(40)])
+            .constraints(o0_constraints)
             .split(o_total_layout[1]);
         let o1_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints(vec![Constraint::Percentage(50), Constraint::Fill(10)])
-            .split(o0_layout[0]);
+            .split(o0_layout[text_col]);
+        // make room under the links for a little preview of whatever is selected
+        let selected_link = self.selected_link();
+        let links_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Max(if selected_link.is_some() { 4 } else { 0 }),
+            ])
+            .split(o1_layout[1]);
         let o2_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints(vec![Constraint::Percentage(80), Constraint::Percentage(20)])
@@ -274,40 +1275,81 @@ impl App {
         let o3_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints(vec![Constraint::Fill(1), Constraint::Max(25)])
-            .split(o0_layout[1]);
+            .split(o0_layout[side_col]);
         let o4_layout = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints(vec![Constraint::Fill(1), Constraint::Max(40)])
+            .constraints(o4_constraints)
             .split(o3_layout[1]);
 
+        #[cfg(feature = "image")]
+        let zoom = self.headshot_zoom.tick();
+        let tick = self.frame_tick();
+
         let help_bar = self.gen_help_bar();
         let tabs_bar = self.gen_nav_bar();
         let title = self.gen_title();
         let mini_about = self.gen_mini_about();
-        let links = self.gen_links();
+        let links = self.gen_links(links_layout[0].width);
+        let extra_links = self.gen_extra_links();
         let about = self.gen_about();
-        let headshot = self.canvas(HEADSHOT, "hey! that's me", [100.0, 500.0], [100.0, 750.0]);
+        #[cfg(feature = "image")]
+        let headshot = self.canvas(
+            &self.headshot_image,
+            self.labels().headshot,
+            zoom_bounds([100.0, 500.0], zoom),
+            zoom_bounds([100.0, 750.0], zoom),
+            self.headshot_colour,
+            self.headshot_inverted,
+            self.headshot_levels,
+        );
+        #[cfg(not(feature = "image"))]
+        let headshot = self.gen_headshot_art(self.labels().headshot);
         let empty = Block::new().bg(self.theme.borrow().color_bg);
 
-        let mut links_state = self
-            .main_state
-            .links_state
-            .lock()
-            .expect("List state poisoned, Someone is messing with the DOM? ");
+        let mut links_state = lock_or_recover(&self.main_state.links_state, "links state");
 
-        let mut tabs_state = self
-            .tabs_state
-            .lock()
-            .expect("Tabs state poisoned, Something is messing with the DOM");
+        let mut tabs_state = lock_or_recover(&self.tabs_state, "tabs state");
+
+        if cfg!(debug_assertions) {
+            warn_on_overlaps(&[
+                ("tabs", o_total_layout[0]),
+                ("title", o2_layout[0]),
+                ("mini about", o2_layout[1]),
+                ("links", links_layout[0]),
+                ("extra links", links_layout[1]),
+                ("link preview", links_layout[2]),
+                ("about", o3_layout[0]),
+                ("headshot", o4_layout[side_col]),
+                ("empty", o4_layout[text_col]),
+            ]);
+        }
 
         frame.render_stateful_widget(tabs_bar, o_total_layout[0], &mut tabs_state);
-        frame.render_widget(help_bar, frame.area());
+        frame.render_widget(help_bar, area);
+        self.render_error(frame, area);
         frame.render_widget(title, o2_layout[0]);
         frame.render_widget(mini_about, o2_layout[1]);
-        frame.render_stateful_widget(links, o1_layout[1], &mut links_state);
+        frame.render_stateful_widget(links, links_layout[0], &mut links_state);
+        frame.render_widget(extra_links, links_layout[1]);
+        if let Some(link) = selected_link {
+            frame.render_widget(self.gen_link_preview(link), links_layout[2]);
+        }
         frame.render_widget(about, o3_layout[0]);
-        frame.render_widget(headshot, o4_layout[1]);
-        frame.render_widget(empty, o4_layout[0]);
+        frame.render_widget(headshot, o4_layout[side_col]);
+        frame.render_widget(empty, o4_layout[text_col]);
+        if self.rain_active() {
+            let colours = (
+                self.theme.borrow().color_fg_alt,
+                self.theme.borrow().color_bg_alt,
+            );
+            self.rain.render(
+                frame.buffer_mut(),
+                o4_layout[text_col],
+                colours,
+                &mut self.rng,
+                tick,
+            );
+        }
         animate!(
             (
                 (self.main_state_animations.title_effect, o2_layout[0]),
@@ -316,101 +1358,1141 @@ impl App {
                 (self.main_state_animations.about_effect, o3_layout[0]),
                 (self.main_state_animations.headshot_effect, o3_layout[1]),
                 (self.main_state_animations.tabs_effect, o_total_layout[0]),
-                (self.main_state_animations.help_effect, frame.area())
+                (self.main_state_animations.help_effect, area)
             ),
             frame,
-            7
+            tick
         );
+        if let Some(idle_effect) = &mut self.idle_effect {
+            // moves on by however long it's really been, frames come far less often for it than the fixed tick assumes
+            let idle_tick = (performance_now() - self.last_render).clamp(0.0, IDLE_FRAME_MS);
+            frame.render_effect(
+                idle_effect,
+                o2_layout[0],
+                Duration::from_millis(idle_tick as u32),
+            );
+        }
+        if self.show_onboarding {
+            self.render_onboarding(frame, area);
+        }
+        if self.touch {
+            self.touch_buttons = self.render_touch_controls(frame, area);
+        }
     }
 
-    fn canvas<'a, const S: usize>(
-        &'a self,
-        image: &'a [u8; S],
-        name: &'a str,
-        width: [f64; 2],
+    // The title art drifts towards another palette colour and back, slowly enough that it just looks alive
+    fn gen_idle_effect(&self) -> Effect {
+        let breath = fx::fade_to_fg(
+            self.theme.borrow().color_5,
+            (IDLE_BREATH_MS, Interpolation::SineInOut),
+        );
+        fx::repeating(fx::ping_pong(breath)).with_filter(CellFilter::Inner(Margin::new(1, 1)))
+    }
+
+    // The page url as a qr code in the middle of the screen, two modules to a cell using half blocks
+    fn render_share_qr(&self, frame: &mut Frame<'_>, code: &QrCode) {
+        let theme = self.theme.borrow();
+        // the quiet zone is part of the code as far as scanners are concerned, so it gets drawn in the light colour too
+        let size = code.width() + 2 * QR_QUIET_ZONE;
+        // scanners want dark modules on a light background, so work out which way round the theme is
+        let brightness = |colour: Color| {
+            let (r, g, b) = colors::to_rgb(colour);
+            u16::from(r) + u16::from(g) + u16::from(b)
+        };
+        let (dark_colour, light_colour) = if brightness(theme.color_bg) < brightness(theme.color_fg)
+        {
+            (theme.color_bg, theme.color_fg)
+        } else {
+            (theme.color_fg, theme.color_bg)
+        };
+        let module = |x: usize, y: usize| {
+            let inside = QR_QUIET_ZONE..QR_QUIET_ZONE + code.width();
+            let dark = inside.contains(&x)
+                && inside.contains(&y)
+                && code[(x - QR_QUIET_ZONE, y - QR_QUIET_ZONE)] == qrcode::Color::Dark;
+            if dark {
+                dark_colour
+            } else {
+                light_colour
+            }
+        };
+        let lines: Vec<Line> = (0..size)
+            .step_by(2)
+            .map(|y| {
+                Line::from(
+                    (0..size)
+                        .map(|x| {
+                            let bottom = if y + 1 < size {
+                                module(x, y + 1)
+                            } else {
+                                light_colour
+                            };
+                            Span::styled("▀", Style::default().fg(module(x, y)).bg(bottom))
+                        })
+                        .collect::<Vec<Span>>(),
+                )
+            })
+            .collect();
+        let qr_block = Block::bordered()
+            .border_type(theme.border_type)
+            .title(self.labels().share_qr)
+            .title_alignment(Alignment::Center)
+            .title_bottom(self.labels().share_qr_hint)
+            .fg(theme.color_fg_alt)
+            .bg(theme.color_bg);
+        let area = centred_rect(frame.area(), size as u16 + 2, size.div_ceil(2) as u16 + 2);
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(qr_block), area);
+    }
+
+    // Every theme in the rotation as a little tile of its name and six colours, for comparing them while designing
+    fn render_theme_sheet(&self, frame: &mut Frame<'_>) {
+        let theme = self.theme.borrow();
+        let sheet = Block::bordered()
+            .border_type(theme.border_type)
+            .title(self.labels().theme_sheet)
+            .title_alignment(Alignment::Center)
+            .title_bottom(self.labels().theme_sheet_hint)
+            .fg(theme.color_fg)
+            .bg(theme.color_bg);
+        let inner = sheet.inner(frame.area());
+        frame.render_widget(sheet, frame.area());
+
+        let columns = (inner.width / THEME_TILE_WIDTH).max(1);
+        for (i, name) in theme.names().into_iter().enumerate() {
+            // a copy of the current theme jumped to each index, so the accent and any custom theme carry over
+            let mut preview = theme.clone();
+            preview.select(i);
+            let (column, row) = (i as u16 % columns, i as u16 / columns);
+            let tile_area = Rect::new(
+                inner.x + column * THEME_TILE_WIDTH,
+                inner.y + row * THEME_TILE_HEIGHT,
+                THEME_TILE_WIDTH,
+                THEME_TILE_HEIGHT,
+            )
+            .intersection(inner);
+            let swatches: Vec<Span> = preview
+                .palette()
+                .iter()
+                .map(|colour| Span::styled("   ", Style::default().bg(*colour)))
+                .collect();
+            let tile = Paragraph::new(Line::from(swatches))
+                .block(
+                    Block::bordered()
+                        .border_type(preview.border_type)
+                        .title(name)
+                        .fg(preview.color_fg)
+                        .bg(preview.color_bg),
+                )
+                .centered();
+            frame.render_widget(tile, tile_area);
+        }
+    }
+
+    // A little box sat just above the help bar so new visitors know where the controls are
+    fn render_onboarding(&self, frame: &mut Frame<'_>, area: Rect) {
+        let hint = Paragraph::new(self.labels().onboarding_hint)
+            .block(
+                Block::bordered()
+                    .border_type(self.theme.borrow().border_type)
+                    .title(self.labels().onboarding)
+                    .title_alignment(Alignment::Right)
+                    .fg(self.theme.borrow().color_fg_alt),
+            )
+            .fg(self.theme.borrow().color_fg)
+            .bg(self.theme.borrow().color_bg)
+            .wrap(Wrap { trim: true });
+        let hint_area =
+            Rect::new(area.x + 1, area.bottom().saturating_sub(5), 36, 4).intersection(area);
+        frame.render_widget(Clear, hint_area);
+        frame.render_widget(hint, hint_area);
+    }
+
+    // A little pad of buttons in the bottom right for touch screens, returns where each button went so taps can find them
+    fn render_touch_controls(&self, frame: &mut Frame<'_>, area: Rect) -> Vec<(Rect, KeyCode)> {
+        let pad_area = Rect::new(
+            area.right().saturating_sub(TOUCH_PAD_WIDTH + 1),
+            area.bottom().saturating_sub(TOUCH_PAD_HEIGHT + 1),
+            TOUCH_PAD_WIDTH,
+            TOUCH_PAD_HEIGHT,
+        )
+        .intersection(area);
+        let pad = Block::bordered()
+            .border_type(self.theme.borrow().border_type)
+            .title(self.labels().touch)
+            .title_alignment(Alignment::Right)
+            .fg(self.theme.borrow().color_fg_alt)
+            .bg(self.theme.borrow().color_bg);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Ratio(1, 2); 2])
+            .split(pad.inner(pad_area));
+        frame.render_widget(Clear, pad_area);
+        frame.render_widget(pad, pad_area);
+
+        let mut buttons = Vec::with_capacity(TOUCH_BUTTONS.len());
+        for (i, (label, code)) in TOUCH_BUTTONS.iter().enumerate() {
+            let columns = Layout::default()
+                .direction(Horizontal)
+                .constraints(vec![Constraint::Ratio(1, 2); 2])
+                .split(rows[i / 2]);
+            let button_area = columns[i % 2];
+            let button = Paragraph::new(*label)
+                .block(Block::bordered().border_type(self.theme.borrow().border_type))
+                .fg(self.theme.borrow().color_fg)
+                .bg(self.theme.borrow().color_bg)
+                .centered();
+            frame.render_widget(button, button_area);
+            buttons.push((button_area, code.clone()));
+        }
+        buttons
+    }
+
+    // Taps on the touch pad act just like pressing the key the button is for
+    fn handle_tap(&mut self, column: u16, row: u16) {
+        let tapped = self
+            .touch_buttons
+            .iter()
+            .find(|(button, _)| button.contains(Position::new(column, row)))
+            .map(|(_, code)| code.clone());
+        if let Some(code) = tapped {
+            self.handle_events(&KeyEvent {
+                code,
+                ctrl: false,
+                alt: false,
+                shift: false,
+            });
+        }
+    }
+
+    #[cfg(feature = "image")]
+    #[allow(clippy::too_many_arguments)]
+    fn canvas<'a>(
+        &'a self,
+        image: &'a DecodedImage,
+        name: &'a str,
+        width: [f64; 2],
         height: [f64; 2],
+        colour_type: ColourType,
+        invert: bool,
+        levels: Option<u8>,
     ) -> impl Widget + 'a {
         Canvas::default()
             .block(
                 Block::bordered()
+                    .border_type(self.theme.borrow().border_type)
                     .title(name)
                     .fg(self.theme.borrow().color_fg)
                     .bg(self.theme.borrow().color_bg),
             )
             .marker(ratzilla::ratatui::symbols::Marker::HalfBlock)
-            .paint(|ctx| {
-                ctx.draw(&ImageShape::new(
-                    image,
-                    self.theme.borrow().color_bg,
-                    ColourType::Grey,
-                ));
+            .paint(move |ctx| {
+                let theme = self.theme.borrow();
+                // two tone screens always get a dithered 1-bit headshot in the background and foreground colours
+                let shape = if theme.is_mono() {
+                    ImageShape::new(
+                        image,
+                        theme.color_headshot,
+                        ColourType::Grey,
+                        invert,
+                        Some(2),
+                    )
+                    .with_shadow(theme.color_bg)
+                } else {
+                    ImageShape::new(image, theme.color_headshot, colour_type, invert, levels)
+                };
+                ctx.draw(&shape);
             })
             .x_bounds(width)
             .y_bounds(height)
     }
 
+    #[cfg(not(feature = "image"))]
+    fn gen_headshot_art<'a>(&self, name: &'a str) -> Paragraph<'a> {
+        Paragraph::new(HEADSHOT_ART)
+            .block(
+                Block::bordered()
+                    .border_type(self.theme.borrow().border_type)
+                    .title(name),
+            )
+            .fg(self.theme.borrow().color_headshot)
+            .bg(self.theme.borrow().color_bg)
+            .centered()
+    }
+
     fn handle_events(&mut self, key_event: &KeyEvent) {
+        self.dirty = true;
+        self.last_input = js_sys::Date::now();
+        self.idle_effect = None;
+        if self.theme_editor.is_some() {
+            self.handle_theme_editor_events(key_event);
+            return;
+        }
+        if self.contact_form.is_some() {
+            self.handle_contact_form_events(key_event);
+            return;
+        }
+        if self.share_qr.is_some() {
+            if matches!(key_event.code, KeyCode::Esc | KeyCode::Char('Q')) {
+                self.share_qr = None;
+            }
+            return;
+        }
+        if self.show_theme_sheet {
+            if key_event.code == KeyCode::Esc {
+                self.show_theme_sheet = false;
+            }
+            return;
+        }
+        if self.show_onboarding {
+            // the first key press just gets rid of the hint, and we never show it again
+            self.show_onboarding = false;
+            storage::set(ONBOARDED_KEY, "true");
+            return;
+        }
+        // returning visitors can skip past the intro by just starting to get around the page
+        // only the intro though, theme and tab switches play out as normal
+        if self.intro_playing
+            && matches!(
+                key_event.code,
+                KeyCode::Up | KeyCode::Down | KeyCode::Enter | KeyCode::Char('j' | 'k')
+            )
+        {
+            self.skip_animations = true;
+            self.intro_playing = false;
+            return;
+        }
         match key_event.code {
-            KeyCode::Up | KeyCode::Char('k') => self
-                .main_state
-                .links_state
-                .lock()
-                .expect("List state poisoned, someone is messing with the DOM?")
-                .select_previous(),
-            KeyCode::Down | KeyCode::Char('j') => self
-                .main_state
-                .links_state
-                .lock()
-                .expect("List state is poisoned, someone is messing with the DOM?")
-                .select_next(),
-            KeyCode::Char('W') => self.cycle_colour(),
-            KeyCode::Enter => {
-                if let Some(v) = self.main_state.links_state.lock().unwrap().selected() {
-                    let url = match v {
-                        0 => "https://github.com/woonters",
-                        1 => "https://youtube.com/@woonters",
-                        2 => "https://twitter.com/woonters",
-                        _ => "https://github.com/woonters",
-                    };
+            KeyCode::Char(c) if !key_event.ctrl && !key_event.alt => {
+                if self.handle_chord(c) {
+                    return;
+                }
+            }
+            // arrows, ctrl combos and the like can't finish a chord, so whatever was held goes first to keep the order
+            _ => {
+                if let Some(first) = self.pending_key.flush() {
+                    self.press_plain_key(first);
+                }
+            }
+        }
+        self.handle_key(key_event);
+    }
+
+    // Holds on to the first key of a chord until the next one comes in
+    //
+    // true means the key got used up by the chord, otherwise it still needs handling on its own
+    fn handle_chord(&mut self, c: char) -> bool {
+        match self.pending_key.press(c, performance_now()) {
+            ChordPress::Held => true,
+            ChordPress::Done(Chord::Top) => {
+                self.jump_link_selection(false);
+                true
+            }
+            ChordPress::Broken(first) => {
+                // not a chord after all, so the held key happens now and this one carries on as normal
+                self.press_plain_key(first);
+                false
+            }
+            ChordPress::Plain => false,
+        }
+    }
+
+    // A held chord key that never got its second half just counts as a normal press once it's waited long enough
+    fn expire_pending_key(&mut self, now: f64) {
+        if let Some(key) = self.pending_key.expire(now) {
+            self.press_plain_key(key);
+            self.dirty = true;
+        }
+    }
+
+    fn press_plain_key(&mut self, key: char) {
+        self.handle_key(&KeyEvent {
+            code: KeyCode::Char(key),
+            ctrl: false,
+            alt: false,
+            shift: false,
+        });
+    }
+
+    // Everything on the page itself, once overlays and chords have had their go
+    fn handle_key(&mut self, key_event: &KeyEvent) {
+        if self.nav_focused && self.handle_nav_key(key_event) {
+            return;
+        }
+        if matches!(self.tab, Tabs::Blog) && self.handle_blog_key(key_event) {
+            return;
+        }
+        match key_event.code {
+            KeyCode::Tab | KeyCode::Char('l') => {
+                self.switch_tab(self.pending_tab.latest(self.tab).step(true));
+            }
+            KeyCode::Char('h') => {
+                self.switch_tab(self.pending_tab.latest(self.tab).step(false));
+            }
+            KeyCode::Char('n') => self.nav_focused = true,
+            KeyCode::Up | KeyCode::Char('k') => self.move_link_selection(false),
+            KeyCode::Down | KeyCode::Char('j') => self.move_link_selection(true),
+            KeyCode::Home => self.jump_link_selection(false),
+            KeyCode::End => self.jump_link_selection(true),
+            KeyCode::Char('W') => {
+                self.cycle_colour();
+            }
+            KeyCode::Char('a') => self.cycle_accent(),
+            KeyCode::Char('E') => self.open_theme_editor(),
+            KeyCode::Char('m') => self.contact_form = Some(ContactForm::default()),
+            KeyCode::Char('Y') => self.copy_page(),
+            KeyCode::Char('Q') => self.open_share_qr(),
+            KeyCode::Char('L') => self.toggle_theme_pair(),
+            KeyCode::Char(digit @ '1'..='9') if key_event.alt => {
+                self.jump_to_theme(digit as usize - '1' as usize);
+            }
+            KeyCode::Char('c') => self.headshot_colour.toggle(),
+            KeyCode::Char('t') if key_event.ctrl => self.show_theme_sheet = true,
+            KeyCode::Char(' ') => self.toggle_effects_paused(),
+            KeyCode::Char('R') => self.toggle_reduced_motion(),
+            KeyCode::Char('e') if key_event.ctrl => self.export_theme(),
+            KeyCode::Char('i') if key_event.ctrl => self.import_theme(),
+            KeyCode::Char('p') if key_event.ctrl => self.save_screenshot(),
+            KeyCode::Char('i') => self.headshot_inverted = !self.headshot_inverted,
+            #[cfg(feature = "image")]
+            KeyCode::Char('q') => self.headshot_levels = next_headshot_levels(self.headshot_levels),
+            #[cfg(feature = "image")]
+            KeyCode::Char('+' | '=') => self.headshot_zoom.zoom_in(),
+            #[cfg(feature = "image")]
+            KeyCode::Char('-') => self.headshot_zoom.zoom_out(),
+            #[cfg(feature = "image")]
+            KeyCode::Char('0') => self.headshot_zoom.reset(),
+            #[cfg(feature = "debug")]
+            KeyCode::Char('s') if key_event.ctrl => self.dump_state(),
+            // ctrl+s is the state dump in debug builds, everywhere else it's left to the browser rather than mirroring
+            KeyCode::Char('s') if !key_event.ctrl => {
+                self.mirrored = !self.mirrored;
+                storage::set(MIRRORED_KEY, &self.mirrored.to_string());
+            }
+            KeyCode::Left => self.move_extra_link_selection(false),
+            KeyCode::Right => self.move_extra_link_selection(true),
+            KeyCode::Char(digit @ '1'..='9') => {
+                let index = digit as usize - '1' as usize;
+                if let Some(url) = link_url(index) {
                     let _ = call_js_function("open", vec![url]);
                 }
             }
+            KeyCode::Enter => {
+                if let Some(link) = self.selected_link() {
+                    let _ = call_js_function("open", vec![link.url]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // The ui strings for whatever language we're in
+    fn labels(&self) -> &'static Labels {
+        self.lang.labels()
+    }
+
+    fn set_status(&mut self, message: impl Into<String>) {
+        self.show_status(message.into(), Severity::Info);
+    }
+
+    // Same as set_status but styled as something having gone wrong
+    fn set_error(&mut self, message: impl Into<String>) {
+        self.show_status(message.into(), Severity::Error);
+    }
+
+    fn show_status(&mut self, message: String, severity: Severity) {
+        self.status = Some(Status {
+            message,
+            severity,
+            until: js_sys::Date::now() + STATUS_DURATION_MS,
+        });
+        self.dirty = true;
+    }
+
+    // Says thanks in the status line as the visitor passes each milestone, once each
+    // Coming back to the tab, time spent in the background shouldn't count towards anything
+    //
+    // effects only move on by a fixed tick per drawn frame so they just carry on where they were,
+    // it's the wall clock timers that would otherwise all go off at once
+    fn resume(&mut self, away_ms: f64) {
+        self.last_input += away_ms;
+        self.session_start += away_ms;
+        if let Some(status) = &mut self.status {
+            status.until += away_ms;
+        }
+        self.idle_effect = None;
+        // the canvas can come back blank after being backgrounded, so don't trust the last frame
+        self.last_frame = None;
+        self.dirty = true;
+    }
+
+    fn check_time_on_site(&mut self) {
+        if self.reduced_motion || self.effects_paused {
+            return;
+        }
+        let Some(after_ms) = TIME_ON_SITE_MILESTONES.get(self.milestones_shown) else {
+            return;
+        };
+        if js_sys::Date::now() - self.session_start >= *after_ms {
+            let message = self.labels().milestones[self.milestones_shown];
+            self.milestones_shown += 1;
+            self.set_status(message);
+        }
+    }
+
+    fn open_share_qr(&mut self) {
+        match page_qr() {
+            Some(code) => self.share_qr = Some(code),
+            None => self.set_error(self.labels().qr_failed),
+        }
+    }
+
+    fn copy_page(&mut self) {
+        match clipboard::copy(&self.as_plain_text()) {
+            Ok(()) => self.set_status(self.labels().copied_page),
+            Err(e) => self.set_error(format!("{}: {e}", self.labels().copy_failed)),
+        }
+    }
+
+    // Downloads the canvas as a png, named after the theme so a few of them can be saved side by side
+    fn save_screenshot(&mut self) {
+        if self.dom_backend {
+            self.set_error(self.labels().screenshot_no_canvas);
+            return;
+        }
+        let filename = format!(
+            "jemma-{}.png",
+            self.theme.borrow().name.to_lowercase().replace(' ', "-")
+        );
+        match screenshot::save_png(&filename) {
+            Ok(()) => self.set_status(format!("{} {filename}", self.labels().screenshot_saved)),
+            Err(e) => self.set_error(format!("{}: {e}", self.labels().screenshot_failed)),
+        }
+    }
+
+    // Puts the current palette on the clipboard so it can be shared
+    fn export_theme(&mut self) {
+        let exported = self.theme.borrow().export_string();
+        match clipboard::copy(&exported) {
+            Ok(()) => self.set_status(self.labels().copied_theme),
+            Err(e) => self.set_error(format!("{}: {e}", self.labels().copy_failed)),
+        }
+    }
+
+    // Asks for a shared palette and, if it reads fine, switches to it and keeps it as the custom theme
+    fn import_theme(&mut self) {
+        // same as the editor, don't swap colours out from under a running transition
+        if !self.transitions_complete() {
+            return;
+        }
+        let Some(pasted) = web_sys::window()
+            .and_then(|w| w.prompt_with_message(self.labels().import_prompt).ok()?)
+        else {
+            return;
+        };
+        match ColourTheme::import_string(&pasted) {
+            Ok(imported) => {
+                {
+                    let mut theme = self.theme.borrow_mut();
+                    theme.set_palette(imported.palette());
+                    theme.color_headshot = imported.color_headshot;
+                    // the borders and rain go too, so it looks the same now as the custom theme will after a reload
+                    theme.border_type = imported.border_type;
+                    theme.background_rain = imported.background_rain;
+                    theme.save_custom();
+                    // and make it the theme we come back to, not just one sat at the end of the rotation
+                    theme.save();
+                }
+                self.update_meta();
+                self.update_page_colours();
+                self.set_status(self.labels().imported_theme);
+            }
+            Err(e) => self.set_error(format!("{}: {e}", self.labels().import_failed)),
+        }
+    }
+
+    fn open_theme_editor(&mut self) {
+        // a theme switch landing half way through editing would trample the edits
+        if !self.transitions_complete() {
+            return;
+        }
+        self.theme_editor = Some(ThemeEditor {
+            slot: 0,
+            channel: 0,
+            original: self.theme.borrow().clone(),
+        });
+    }
+
+    // While the editor is open it gets every key press, edits go straight onto the live theme
+    fn handle_theme_editor_events(&mut self, key_event: &KeyEvent) {
+        let Some(editor) = self.theme_editor.as_mut() else {
+            return;
+        };
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                editor.slot = (editor.slot + SLOT_NAMES.len() - 1) % SLOT_NAMES.len();
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                editor.slot = (editor.slot + 1) % SLOT_NAMES.len();
+            }
+            KeyCode::Tab => editor.channel = (editor.channel + 1) % 3,
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l') => {
+                let delta = if matches!(key_event.code, KeyCode::Left | KeyCode::Char('h')) {
+                    -THEME_EDITOR_STEP
+                } else {
+                    THEME_EDITOR_STEP
+                };
+                let mut theme = self.theme.borrow_mut();
+                if let Some(colour) = theme.slot_mut(editor.slot) {
+                    *colour = colors::adjust_channel(*colour, editor.channel, delta);
+                }
+            }
+            KeyCode::Enter => {
+                self.theme.borrow_mut().save_custom();
+                self.theme_editor = None;
+            }
+            KeyCode::Esc => {
+                *self.theme.borrow_mut() = editor.original.clone();
+                self.theme_editor = None;
+            }
+            _ => return,
+        }
+        self.update_page_colours();
+    }
+
+    // While the form is open everything typed goes into it, tab swaps between the fields
+    fn handle_contact_form_events(&mut self, key_event: &KeyEvent) {
+        let Some(form) = self.contact_form.as_mut() else {
+            return;
+        };
+        let field = if form.on_message {
+            &mut form.message
+        } else {
+            &mut form.subject
+        };
+        match key_event.code {
+            KeyCode::Esc => self.contact_form = None,
+            KeyCode::Tab => form.on_message = !form.on_message,
+            KeyCode::Enter if key_event.ctrl => self.send_contact_form(),
+            // a new line in the message, in the subject it just moves down to the message
+            KeyCode::Enter if form.on_message => form.message.push('\n'),
+            KeyCode::Enter => form.on_message = true,
+            KeyCode::Backspace => {
+                field.pop();
+            }
+            KeyCode::Char(c) if !key_event.ctrl && !key_event.alt => field.push(c),
             _ => {}
         }
     }
 
-    fn cycle_colour(&mut self) {
+    // Opens the mail client with the form filled in, the form stays open if there's nothing to send
+    fn send_contact_form(&mut self) {
+        let Some(form) = &self.contact_form else {
+            return;
+        };
+        if form.subject.trim().is_empty() && form.message.trim().is_empty() {
+            self.set_error(self.labels().contact_empty);
+            return;
+        }
+        match contact::open_mailto(&form.subject, &form.message) {
+            Ok(()) => {
+                self.contact_form = None;
+                self.set_status(self.labels().contact_sent);
+            }
+            Err(e) => self.set_error(format!("{}: {e}", self.labels().contact_failed)),
+        }
+    }
+
+    // Keep the page title and link preview tags in line with whatever we're currently showing
+    fn update_meta(&self) {
+        let post = match self.tab {
+            Tabs::Blog => self.selected_post(),
+            Tabs::Main => None,
+        };
+        let (title, description) = page_meta(self.tab, post);
+        meta::set_page_meta(&title, description);
+    }
+
+    // Puts the headshot's description in the page for screen readers, needs calling again if the language changes
+    fn update_headshot_alt(&self) {
+        meta::set_hidden_text(HEADSHOT_ALT_ID, self.labels().headshot_alt);
+    }
+
+    // Let the html around the canvas pick up the theme colours too
+    fn update_page_colours(&self) {
+        meta::set_css_colours(&self.theme.borrow().to_hex_map());
+    }
+
+    fn move_link_selection(&mut self, forward: bool) {
+        self.main_state.extra_link = None;
+        let mut links_state = lock_or_recover(&self.main_state.links_state, "links state");
+        let next = step_selection(
+            links_state.selected(),
+            LINKS.len(),
+            forward,
+            self.clamp_lists,
+        );
+        links_state.select(next);
+    }
+
+    // Home and End, straight to the first or last link
+    fn jump_link_selection(&mut self, to_end: bool) {
+        self.main_state.extra_link = None;
+        let mut links_state = lock_or_recover(&self.main_state.links_state, "links state");
+        let target = if to_end {
+            LINKS.len().checked_sub(1)
+        } else {
+            Some(0)
+        };
+        links_state.select(target.filter(|i| *i < LINKS.len()));
+    }
+
+    // Left and right move along the compact row, taking the selection away from the main list
+    fn move_extra_link_selection(&mut self, forward: bool) {
+        lock_or_recover(&self.main_state.links_state, "links state").select(None);
+        self.main_state.extra_link = step_selection(
+            self.main_state.extra_link,
+            EXTRA_LINKS.len(),
+            forward,
+            self.clamp_lists,
+        );
+    }
+
+    // Whichever link is selected, from the main list or the compact row
+    fn selected_link(&self) -> Option<&'static Link> {
+        let selected = lock_or_recover(&self.main_state.links_state, "links state").selected();
+        match selected {
+            Some(i) => LINKS.get(i),
+            None => self.main_state.extra_link.and_then(|i| EXTRA_LINKS.get(i)),
+        }
+    }
+
+    fn render_blog(&mut self, frame: &mut Frame<'_>) {
+        let Some(area) = self.page_area(frame) else {
+            return;
+        };
+        let total_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Max(3),
+                Constraint::Fill(2),
+                Constraint::Max(2),
+            ])
+            .split(area);
+        // post list down the left, whatever's open takes up the rest
+        let content_layout = Layout::default()
+            .direction(Horizontal)
+            .constraints(vec![Constraint::Percentage(30), Constraint::Fill(1)])
+            .split(total_layout[1]);
+
+        let tick = self.frame_tick();
+
+        let help_bar = self.gen_help_bar();
+        let tabs_bar = self.gen_nav_bar();
+        let posts = self.gen_posts();
+        let content = self.gen_post_content();
+
+        let mut posts_state = lock_or_recover(&self.blog_state.posts_state, "posts state");
+        let mut tabs_state = lock_or_recover(&self.tabs_state, "tabs state");
+
+        frame.render_stateful_widget(tabs_bar, total_layout[0], &mut tabs_state);
+        frame.render_widget(help_bar, area);
+        self.render_error(frame, area);
+        frame.render_stateful_widget(posts, content_layout[0], &mut posts_state);
+        frame.render_widget(content, content_layout[1]);
+        // less the border top and bottom
+        self.blog_state.viewport = content_layout[1].height.saturating_sub(2);
+        animate!(
+            (
+                (self.blog_state_animations.tabs_effect, total_layout[0]),
+                (self.blog_state_animations.posts_effect, content_layout[0]),
+                (self.blog_state_animations.content_effect, content_layout[1]),
+                (self.blog_state_animations.help_effect, area)
+            ),
+            frame,
+            tick
+        );
+    }
+
+    // The post titles, newest first
+    fn gen_posts(&self) -> List<'_> {
+        let posts_block = Block::bordered()
+            .title_alignment(Alignment::Center)
+            .border_type(self.theme.borrow().border_type)
+            .title(self.labels().posts);
+        List::new(POSTS.iter().map(|post| post.title))
+            .block(posts_block)
+            .fg(self.theme.borrow().color_fg)
+            .bg(self.theme.borrow().color_bg)
+            .highlight_symbol(">")
+            .highlight_style(self.gen_highlight_style())
+    }
+
+    // The selected post, or a note saying there's nothing here yet
+    fn gen_post_content(&self) -> Paragraph<'_> {
+        let content_block = Block::bordered()
+            .border_type(self.theme.borrow().border_type)
+            .fg(self.theme.borrow().color_fg);
+        let paragraph = match self.selected_post() {
+            Some(post) => Paragraph::new(post.body)
+                .block(content_block.title(post.title))
+                .scroll((self.blog_state.scroll, 0)),
+            None if POSTS.is_empty() => Paragraph::new(self.labels().no_posts)
+                .block(content_block)
+                .centered(),
+            None => Paragraph::new("").block(content_block),
+        };
+        paragraph
+            .fg(self.theme.borrow().color_fg_alt)
+            .bg(self.theme.borrow().color_bg)
+            .wrap(Wrap { trim: true })
+    }
+
+    fn selected_post(&self) -> Option<&'static Post> {
+        lock_or_recover(&self.blog_state.posts_state, "posts state")
+            .selected()
+            .and_then(|i| POSTS.get(i))
+    }
+
+    // Show `tab`, playing its entrance again so the switch doesn't just snap over
+    //
+    // waits for any transition to finish first, a theme switch left half way on the tab we leave would never land,
+    // render picks the switch up again once it's done
+    fn switch_tab(&mut self, tab: Tabs) {
+        // the nav bar shows where we're going straight away, even if it has to wait
+        self.highlight_tab(tab);
+        let Some(tab) = self.pending_tab.request(tab, self.transitions_complete()) else {
+            return;
+        };
+        // already there, replaying the entrance would just look like a glitch
+        if tab == self.tab {
+            return;
+        }
+        self.tab = tab;
+        lock_or_recover(&self.tabs_state, "tabs state").select(Some(tab.index()));
+        let transition = self.theme.borrow().transition();
+        match tab {
+            Tabs::Main => {
+                let tx = self.main_state_animations.tx.take();
+                self.main_state_animations = MainAnimationState::entrance(transition);
+                self.main_state_animations.tx = tx;
+            }
+            Tabs::Blog => {
+                let tx = self.blog_state_animations.tx.take();
+                self.blog_state_animations = BlogAnimationState::entrance(transition);
+                self.blog_state_animations.tx = tx;
+            }
+        }
+        // same as a theme switch, with effects paused the entrance still runs, just all in one frame
+        if self.effects_paused {
+            self.skip_animations = true;
+        }
+        self.idle_effect = None;
+        self.update_meta();
+    }
+
+    // Keys while the nav bar has focus, moving the highlight round the tabs and Enter to go to it
+    //
+    // true if the key got used here, anything else takes the focus away and then carries on as normal
+    fn handle_nav_key(&mut self, key_event: &KeyEvent) -> bool {
+        let highlighted = lock_or_recover(&self.tabs_state, "tabs state")
+            .selected()
+            .and_then(|i| Tabs::ALL.get(i).copied())
+            .unwrap_or(self.tab);
+        match key_event.code {
+            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
+                self.highlight_tab(highlighted.step(true));
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.highlight_tab(highlighted.step(false)),
+            KeyCode::Enter => {
+                self.nav_focused = false;
+                self.switch_tab(highlighted);
+            }
+            KeyCode::Esc | KeyCode::Char('n') => self.blur_nav(),
+            _ => {
+                self.blur_nav();
+                return false;
+            }
+        }
+        true
+    }
+
+    fn highlight_tab(&self, tab: Tabs) {
+        lock_or_recover(&self.tabs_state, "tabs state").select(Some(tab.index()));
+    }
+
+    // Gives the focus back to the page, the highlight goes back to the tab we're on (or on our way to)
+    fn blur_nav(&mut self) {
+        self.nav_focused = false;
+        self.highlight_tab(self.pending_tab.latest(self.tab));
+    }
+
+    // Keys that mean something different on the blog, true if the key got used here
+    fn handle_blog_key(&mut self, key_event: &KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::Down | KeyCode::Char('j') => {
+                let forward = matches!(key_event.code, KeyCode::Down | KeyCode::Char('j'));
+                {
+                    let mut posts_state =
+                        lock_or_recover(&self.blog_state.posts_state, "posts state");
+                    let next = step_selection(
+                        posts_state.selected(),
+                        POSTS.len(),
+                        forward,
+                        self.clamp_lists,
+                    );
+                    posts_state.select(next);
+                }
+                // a different post starts back at the top, and shows its own title in the browser tab
+                self.blog_state.scroll = 0;
+                self.update_meta();
+            }
+            KeyCode::Char('J') => {
+                self.blog_state.scroll = self.blog_state.scroll.saturating_add(self.scroll_step);
+            }
+            KeyCode::Char('K') => {
+                self.blog_state.scroll = self.blog_state.scroll.saturating_sub(self.scroll_step);
+            }
+            KeyCode::PageDown => {
+                let step = page_step(self.blog_state.viewport);
+                self.blog_state.scroll = self.blog_state.scroll.saturating_add(step);
+            }
+            KeyCode::PageUp => {
+                let step = page_step(self.blog_state.viewport);
+                self.blog_state.scroll = self.blog_state.scroll.saturating_sub(step);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    // Stand in for the main screen when it's too cramped to lay out, the nested layouts just collapse to nothing otherwise
+    // Fills the screen with the background and works out where a tab's content goes
+    //
+    // None when the screen is too small for any of it, the ask for a bigger window has been drawn instead
+    fn page_area(&self, frame: &mut Frame<'_>) -> Option<Rect> {
+        if too_small(frame.area()) {
+            self.render_too_small(frame);
+            return None;
+        }
+        // on really wide screens keep everything in a readable column down the middle, with plain background either side
+        let area = if self.fixed_grid {
+            centred_rect(frame.area(), FIXED_GRID_WIDTH, FIXED_GRID_HEIGHT)
+        } else {
+            centred_rect(frame.area(), MAX_CONTENT_WIDTH, frame.area().height)
+        };
+        frame.render_widget(Block::new().bg(self.theme.borrow().color_bg), frame.area());
+        Some(area)
+    }
+
+    // Any error on the status line gets a banner sat on top of the help bar in `area`
+    fn render_error(&self, frame: &mut Frame<'_>, area: Rect) {
+        if let Some(status) = self
+            .status
+            .as_ref()
+            .filter(|s| s.severity == Severity::Error)
+        {
+            frame.render_widget(
+                error_banner(&status.message, &self.theme.borrow()),
+                error_banner_area(&status.message, area),
+            );
+        }
+    }
+
+    fn render_too_small(&self, frame: &mut Frame<'_>) {
+        render_too_small_message(frame, self.labels().too_small, &self.theme.borrow());
+    }
+
+    // Whether any effect at all is still going, the pane transitions or the idle one
+    #[cfg(feature = "debug")]
+    fn effects_running(&self) -> bool {
+        self.main_state_animations.any_running()
+            || self.blog_state_animations.any_running()
+            || self
+                .idle_effect
+                .as_ref()
+                .is_some_and(|effect| effect.running())
+    }
+
+    // A single dot in the top left corner that lights up while effects are running, to check they actually stop
+    #[cfg(feature = "debug")]
+    fn render_busy_dot(&self, frame: &mut Frame) {
+        let colour = if self.effects_running() {
+            self.theme.borrow().color_fg_alt
+        } else {
+            self.theme.borrow().color_bg_alt
+        };
+        let area = frame.area();
+        if let Some(cell) = frame.buffer_mut().cell_mut((area.x, area.y)) {
+            cell.set_char('●').set_fg(colour);
+        }
+    }
+
+    // Log everything needed to reproduce what's on screen, for bug reports
+    #[cfg(feature = "debug")]
+    fn dump_state(&self) {
+        let snapshot = StateSnapshot {
+            tab: self.tab.meta().0,
+            theme: self.theme.borrow().name.clone(),
+            accent: self.theme.borrow().accent(),
+            selected_link: lock_or_recover(&self.main_state.links_state, "links state").selected(),
+            selected_tab: lock_or_recover(&self.tabs_state, "tabs state").selected(),
+            headshot_full_colour: matches!(self.headshot_colour, ColourType::Full),
+            headshot_inverted: self.headshot_inverted,
+            #[cfg(feature = "image")]
+            headshot_zoom: self.headshot_zoom.target,
+            mirrored: self.mirrored,
+            clamp_lists: self.clamp_lists,
+            show_onboarding: self.show_onboarding,
+            animating: !self.transitions_complete(),
+            dropped_colour_events: DROPPED_COLOUR_EVENTS.load(Ordering::Relaxed),
+        };
+        match serde_json::to_string_pretty(&snapshot) {
+            Ok(json) => web_sys::console::log_1(&json.into()),
+            Err(e) => web_sys::console::error_1(&format!("couldn't dump state: {e}").into()),
+        }
+    }
+
+    fn cycle_accent(&mut self) {
+        self.theme.borrow_mut().cycle_accent();
+        storage::set(ACCENT_KEY, &self.theme.borrow().accent().to_string());
+        self.update_page_colours();
+    }
+
+    // Slide over to the next theme, false if it had to be dropped as the last transition was still going
+    fn cycle_colour(&mut self) -> bool {
+        // starting a new transition while the last one is still settling would stack them up, so wait for it to finish
+        if !self.transitions_complete() {
+            return false;
+        }
         let bg_1_old = self.theme.borrow().color_bg;
-        self.main_state_animations
-            .create_fresh_animations(bg_1_old, &mut self.rng);
+        let transition = self.theme.borrow().next_transition();
+        // only the tab on screen gets drawn, so it's the one that has to carry the colour switch
+        match self.tab {
+            Tabs::Main => self.main_state_animations.create_fresh_animations(
+                bg_1_old,
+                &mut self.rng,
+                self.last_area,
+                transition,
+            ),
+            Tabs::Blog => self.blog_state_animations.create_fresh_animations(
+                bg_1_old,
+                &mut self.rng,
+                self.last_area,
+                transition,
+            ),
+        }
+        // with effects paused the switch still goes through the animations, just all in one frame
+        if self.effects_paused {
+            self.skip_animations = true;
+        }
+        true
+    }
+
+    // Straight to the theme at `index`, sliding over to it like W does
+    fn jump_to_theme(&mut self, index: usize) {
+        if index >= self.theme.borrow().theme_count() || !self.transitions_complete() {
+            return;
+        }
+        self.theme.borrow_mut().queue(index);
+        self.cycle_colour();
+    }
+
+    // Flip between light and dark versions of the current theme, if it has one
+    fn toggle_theme_pair(&mut self) {
+        if !self.transitions_complete() {
+            return;
+        }
+        if !self.theme.borrow_mut().toggle_pair() {
+            self.set_status(self.labels().no_theme_pair);
+            return;
+        }
+        self.theme.borrow().save();
+        self.update_meta();
+        self.update_page_colours();
+    }
+
+    // How far this frame moves the effects along
+    //
+    // skipping is just one huge tick, which runs every effect through to its final state this frame,
+    // with reduced motion every frame is like that so slides and fades land straight on their final colours
+    fn frame_tick(&mut self) -> u32 {
+        if std::mem::take(&mut self.skip_animations) || self.reduced_motion {
+            SKIP_TICK_MS
+        } else {
+            FRAME_TICK_MS
+        }
+    }
+
+    fn toggle_reduced_motion(&mut self) {
+        self.reduced_motion = !self.reduced_motion;
+        storage::set(REDUCED_MOTION_KEY, &self.reduced_motion.to_string());
+        if self.reduced_motion {
+            self.idle_effect = None;
+            self.set_status(self.labels().motion_reduced);
+        } else {
+            self.set_status(self.labels().motion_on);
+        }
+    }
+
+    fn toggle_effects_paused(&mut self) {
+        self.effects_paused = !self.effects_paused;
+        if self.effects_paused {
+            // finish off anything already running so nothing is left stuck half way
+            self.skip_animations = true;
+            self.idle_effect = None;
+            self.set_status(self.labels().effects_paused);
+        } else {
+            self.set_status(self.labels().effects_on);
+        }
     }
 
     fn gen_instructions(&'_ self) -> Line<'_> {
-        Line::from(vec![
-            " Switch colour theme ".into(),
-            "<W>".fg(self.theme.borrow().color_fg_alt).bold(),
-            " Next List Item ".into(),
-            "<j>".fg(self.theme.borrow().color_fg_alt).bold(),
-            " Previous List Item".into(),
-            "<k>".fg(self.theme.borrow().color_fg_alt).bold(),
-            " Select List Item ".into(),
-            "<enter>".fg(self.theme.borrow().color_fg_alt).bold(),
-        ])
-    }
-
-    fn gen_help_bar(&self) -> Block {
-        Block::bordered()
+        let key_colour = self.theme.borrow().color_fg_alt;
+        Line::from(
+            self.labels()
+                .instructions
+                .iter()
+                .flat_map(|&(action, key)| [action.into(), key.fg(key_colour).bold()])
+                .collect::<Vec<Span>>(),
+        )
+    }
+
+    fn gen_help_bar(&self) -> Block<'_> {
+        let mut help_bar = Block::bordered()
+            .border_type(self.theme.borrow().border_type)
             .title_bottom(self.gen_instructions())
             .fg(self.theme.borrow().color_fg)
-            .bg(self.theme.borrow().color_bg)
+            .bg(self.theme.borrow().color_bg);
+        // a theme switch takes a moment to slide through, so show that something is happening
+        if !self.transitions_complete() {
+            help_bar = help_bar.title(Line::from(self.busy_indicator()).right_aligned());
+        }
+        match &self.status {
+            Some(status) if status.severity == Severity::Info => help_bar.title_bottom(
+                Line::from(format!(" {} ", status.message))
+                    .fg(self.theme.borrow().color_fg_alt)
+                    .right_aligned(),
+            ),
+            _ => help_bar,
+        }
+    }
+
+    // Spins while things are busy, or just sits still if motion is turned down
+    fn busy_indicator(&self) -> Span<'static> {
+        if self.reduced_motion || self.effects_paused {
+            spinner::still(&self.theme.borrow())
+        } else {
+            spinner::spinner(performance_now(), &self.theme.borrow())
+        }
     }
 
     fn gen_title(&self) -> Paragraph<'_> {
         let title_block = Block::bordered()
             .title_alignment(Alignment::Center)
-            .border_type(BorderType::Rounded)
+            .border_type(self.theme.borrow().border_type)
             .fg(self.theme.borrow().color_fg)
-            .title("WhoamI?");
+            .title(self.labels().title);
 
         Paragraph::new(TITLE_ART)
             .block(title_block)
@@ -422,9 +2504,9 @@ impl App {
     fn gen_mini_about(&self) -> Paragraph<'_> {
         let mini_about_block = Block::bordered()
             .title_alignment(Alignment::Center)
-            .border_type(BorderType::Rounded)
-            .title("WhatAmI??");
-        let text = text::Span::raw("Hi, I'm Jemma (She / Her), come look at my silly things :p");
+            .border_type(self.theme.borrow().border_type)
+            .title(self.labels().mini_about);
+        let text = text::Span::raw(self.labels().mini_about_text);
 
         Paragraph::new(text)
             .block(mini_about_block)
@@ -434,40 +2516,117 @@ impl App {
             .centered()
     }
 
+    // Selected list rows get the alt colours so it's obvious where you are
+    fn gen_highlight_style(&self) -> Style {
+        // in two tone mode the alt colours are the same as the normal ones, so flip them instead
+        if self.theme.borrow().is_mono() {
+            return Style::default()
+                .fg(self.theme.borrow().color_bg)
+                .bg(self.theme.borrow().color_fg);
+        }
+        Style::default()
+            .fg(self.theme.borrow().color_fg_alt)
+            .bg(self.theme.borrow().color_bg_alt)
+    }
+
     fn gen_nav_bar(&self) -> List<'_> {
-        let nav_block = Block::bordered()
-            .title("Navigation")
-            .border_type(BorderType::Rounded);
-        let tabs_list = vec!["Main", "Blog"];
+        let mut nav_block = Block::bordered()
+            .title(self.labels().navigation)
+            .border_type(self.theme.borrow().border_type);
+        // picked out while it has focus, so it's clear the keys are going to it
+        if self.nav_focused {
+            nav_block =
+                nav_block.border_style(Style::default().fg(self.theme.borrow().color_fg_alt));
+        }
+        let tabs_list = self.labels().tabs.to_vec();
         List::new(tabs_list)
             .block(nav_block)
             .fg(self.theme.borrow().color_fg)
             .bg(self.theme.borrow().color_bg)
             .highlight_symbol(">")
+            .highlight_style(self.gen_highlight_style())
             .repeat_highlight_symbol(true)
     }
 
-    fn gen_links(&self) -> List<'_> {
+    // `width` is the whole block, labels that won't fit in what's left of it get cut short with an ellipsis
+    fn gen_links(&self, width: u16) -> List<'_> {
+        // borders either side, the highlight symbol and the icon column all come out of the room for the label
+        let label_width = usize::from(width).saturating_sub(3 + LINK_ICON_WIDTH);
         let links_block = Block::bordered()
             .title_alignment(Alignment::Center)
-            .border_type(BorderType::Rounded)
-            .title("Links");
-        let links_list = vec!["Github", "Youtube", "Twitter"];
+            .border_type(self.theme.borrow().border_type)
+            .title(self.labels().links);
+        let links_list = LINKS.iter().map(|link| {
+            let line = Line::from(vec![
+                Span::styled(
+                    pad_to_width(link.icon, LINK_ICON_WIDTH),
+                    Style::default().fg(self.theme.borrow().color_fg_alt),
+                ),
+                Span::raw(truncate_with_ellipsis(link.label, label_width)),
+            ]);
+            // spacing is blank lines tacked onto each item, that way the spacers can't be selected on their own
+            let mut item = Text::from(line);
+            item.extend((0..self.list_spacing).map(|_| Line::default()));
+            ListItem::new(item)
+        });
         List::new(links_list)
             .block(links_block)
             .fg(self.theme.borrow().color_fg)
             .bg(self.theme.borrow().color_bg)
             .highlight_symbol(">")
-            .repeat_highlight_symbol(true)
+            .highlight_style(self.gen_highlight_style())
+            .repeat_highlight_symbol(self.list_spacing == 0)
+    }
+
+    // One dimmed line of short labels, so it reads as an afterthought to the main list
+    fn gen_extra_links(&self) -> Paragraph<'_> {
+        let mut spans = Vec::new();
+        for (i, link) in EXTRA_LINKS.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(EXTRA_LINK_SEPARATOR));
+            }
+            let label = Span::raw(link.label);
+            spans.push(if self.main_state.extra_link == Some(i) {
+                label.style(self.gen_highlight_style())
+            } else {
+                label
+            });
+        }
+        Paragraph::new(Line::from(spans))
+            .fg(self.theme.borrow().color_fg)
+            .bg(self.theme.borrow().color_bg)
+            .add_modifier(Modifier::DIM)
+            .centered()
+    }
+
+    fn gen_link_preview(&self, link: &Link) -> Paragraph<'_> {
+        let preview_block = Block::bordered()
+            .border_type(self.theme.borrow().border_type)
+            .fg(self.theme.borrow().color_fg)
+            .title(link.url);
+        Paragraph::new(link.description)
+            .block(preview_block)
+            .fg(self.theme.borrow().color_fg_alt)
+            .bg(self.theme.borrow().color_bg)
+            .wrap(Wrap { trim: true })
     }
 
     fn gen_about(&self) -> Paragraph<'_> {
         let about_block = Block::bordered()
             .title_alignment(Alignment::Left)
-            .border_type(BorderType::Rounded)
+            .border_type(self.theme.borrow().border_type)
             .fg(self.theme.borrow().color_fg)
-            .title("About");
-        let about_text = vec![
+            .title(self.labels().about);
+        Paragraph::new(self.about_text())
+            .block(about_block)
+            .fg(self.theme.borrow().color_fg_alt)
+            .bg(self.theme.borrow().color_bg)
+            .centered()
+            .wrap(ratzilla::ratatui::widgets::Wrap { trim: true })
+    }
+
+    fn about_text(&self) -> Vec<Line<'_>> {
+        vec![
             text::Line::from(
                 vec![ Span::from("I'm "),
                     Span::styled("Jemma",Style::default().fg(self.theme.borrow().color_fg)),
@@ -491,32 +2650,302 @@ impl App {
                   Span::from(". But coding isn't my only hobby, I've recently been making music, 3d modeling, animating and writing."),
               ]
           ),
-        ];
-        Paragraph::new(about_text)
-            .block(about_block)
-            .fg(self.theme.borrow().color_fg_alt)
-            .bg(self.theme.borrow().color_bg)
-            .centered()
-            .wrap(ratzilla::ratatui::widgets::Wrap { trim: true })
+        ]
+    }
+
+    /// Everything readable on the current view as plain text, for copying out
+    fn as_plain_text(&self) -> String {
+        match self.tab {
+            Tabs::Main => {
+                let links = LINKS
+                    .iter()
+                    .chain(&EXTRA_LINKS)
+                    .map(|link| format!("{}: {}", link.label, link.url));
+                [
+                    self.labels().mini_about_text.to_string(),
+                    lines_to_plain_text(&self.about_text()),
+                ]
+                .into_iter()
+                .chain(links)
+                .collect::<Vec<_>>()
+                .join("\n\n")
+            }
+            Tabs::Blog => self
+                .selected_post()
+                .map(|post| format!("{}\n\n{}", post.title, post.body))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Flattens styled lines down to just their text, one line each
+fn lines_to_plain_text(lines: &[Line]) -> String {
+    lines
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Works out where a list selection ends up after moving one item forwards or backwards
+///
+/// Going past either end wraps round to the other end, unless `clamp` is set in which case we stay put
+fn step_selection(
+    selected: Option<usize>,
+    len: usize,
+    forward: bool,
+    clamp: bool,
+) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let next = match (selected, forward) {
+        (None, true) => 0,
+        (None, false) => last,
+        (Some(i), true) if i >= last => {
+            if clamp {
+                last
+            } else {
+                0
+            }
+        }
+        (Some(i), true) => i + 1,
+        (Some(0), false) => {
+            if clamp {
+                0
+            } else {
+                last
+            }
+        }
+        (Some(i), false) => (i - 1).min(last),
+    };
+    Some(next)
+}
+
+/// Whether `area` is under the smallest size the layouts still make sense at
+fn too_small(area: Rect) -> bool {
+    area.width < MIN_MAIN_WIDTH || area.height < MIN_MAIN_HEIGHT
+}
+
+/// Fills the whole frame with a request for a bigger window, wrapped to fit however narrow it is
+fn render_too_small_message(frame: &mut Frame<'_>, text: &str, theme: &ColourTheme) {
+    let area = frame.area();
+    // a rough count of the wrapped lines, with one spare since word wrapping can need it
+    let lines = display_width(text).div_ceil(usize::from(area.width.max(1))) + 1;
+    let message = Paragraph::new(text)
+        .fg(theme.color_fg)
+        .bg(theme.color_bg)
+        .centered()
+        .wrap(Wrap { trim: true });
+    frame.render_widget(Block::new().bg(theme.color_bg), area);
+    frame.render_widget(message, centred_rect(area, area.width, lines as u16));
+}
+
+/// A `width` by `height` rect in the middle of `area`, shrunk to fit if `area` is too small
+fn centred_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// How many columns `s` takes up on screen, emoji and CJK characters count for two
+fn display_width(s: &str) -> usize {
+    UnicodeWidthStr::width(s)
+}
+
+/// Pads `s` with spaces out to `width` columns, format! pads by chars which comes up short for wide ones
+fn pad_to_width(s: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(s));
+    format!("{s}{}", " ".repeat(padding))
+}
+
+/// Cuts `s` down to fit in `max` columns, swapping the end for `…` when anything had to go
+///
+/// A wide character that would straddle the edge is dropped whole rather than split
+fn truncate_with_ellipsis(s: &str, max: usize) -> String {
+    if display_width(s) <= max {
+        return s.to_string();
+    }
+    let Some(room) = max.checked_sub(1) else {
+        return String::new();
+    };
+    let mut truncated = String::new();
+    let mut width = 0;
+    for c in s.chars() {
+        let c_width = UnicodeWidthChar::width(c).unwrap_or(0);
+        if width + c_width > room {
+            break;
+        }
+        width += c_width;
+        truncated.push(c);
+    }
+    truncated.push('…');
+    truncated
+}
+
+/// Whether two rects share any cells, empty rects never overlap anything
+fn rects_overlap(a: Rect, b: Rect) -> bool {
+    !a.intersection(b).is_empty()
+}
+
+/// Logs a console warning for every pair of panes that would paint over each other, a layout mistake is the only way this happens
+fn warn_on_overlaps(panes: &[(&str, Rect)]) {
+    for (i, (name_a, a)) in panes.iter().enumerate() {
+        for (name_b, b) in &panes[i + 1..] {
+            if rects_overlap(*a, *b) {
+                web_sys::console::warn_1(
+                    &format!("layout: {name_a} {a} overlaps {name_b} {b}").into(),
+                );
+            }
+        }
+    }
+}
+
+// Limits on the headshot zoom, how much each key press zooms by, and how quickly we ease towards it each frame
+#[cfg(feature = "image")]
+const MIN_ZOOM: f64 = 0.5;
+#[cfg(feature = "image")]
+const MAX_ZOOM: f64 = 4.0;
+#[cfg(feature = "image")]
+const ZOOM_STEP: f64 = 1.25;
+#[cfg(feature = "image")]
+const ZOOM_EASING: f64 = 0.2;
+// once the zoom is this close to the target it just jumps the rest of the way
+#[cfg(feature = "image")]
+const ZOOM_SNAP: f64 = 0.001;
+
+// Grey levels q steps the headshot through, after the last one it goes back to smooth
+#[cfg(feature = "image")]
+const HEADSHOT_LEVELS: [u8; 5] = [2, 4, 8, 16, 32];
+
+/// The next posterisation level after `current`, smooth comes round again after the most levels
+#[cfg(feature = "image")]
+fn next_headshot_levels(current: Option<u8>) -> Option<u8> {
+    match current {
+        None => HEADSHOT_LEVELS.first().copied(),
+        Some(levels) => HEADSHOT_LEVELS.iter().copied().find(|l| *l > levels),
+    }
+}
+
+// 4x4 ordered dither pattern, spreads the rounding error between levels so posterised bands blend a little
+#[cfg(feature = "image")]
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Snaps a brightness `h` (0 to 1) down to one of `levels` evenly spaced steps, dithered by where the pixel is
+#[cfg(feature = "image")]
+fn quantise(h: f64, levels: u8, x: usize, y: usize) -> f64 {
+    let steps = f64::from(levels.max(2) - 1);
+    let threshold = (f64::from(BAYER_4X4[y % 4][x % 4]) + 0.5) / 16.0 - 0.5;
+    ((h * steps + threshold).round().clamp(0.0, steps)) / steps
+}
+
+// How far the headshot is zoomed in, the shown zoom eases towards the target one so it doesn't just snap
+#[cfg(feature = "image")]
+struct Zoom {
+    target: f64,
+    current: f64,
+}
+
+#[cfg(feature = "image")]
+impl Default for Zoom {
+    fn default() -> Self {
+        Self {
+            target: 1.0,
+            current: 1.0,
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl Zoom {
+    fn zoom_in(&mut self) {
+        self.target = (self.target * ZOOM_STEP).min(MAX_ZOOM);
+    }
+
+    fn zoom_out(&mut self) {
+        self.target = (self.target / ZOOM_STEP).max(MIN_ZOOM);
+    }
+
+    fn reset(&mut self) {
+        self.target = 1.0;
+    }
+
+    /// Move the shown zoom a step closer to the target and return it
+    fn tick(&mut self) -> f64 {
+        self.current += (self.target - self.current) * ZOOM_EASING;
+        if (self.target - self.current).abs() < ZOOM_SNAP {
+            self.current = self.target;
+        }
+        self.current
+    }
+
+    /// Whether the shown zoom has caught up with the target
+    fn settled(&self) -> bool {
+        self.current == self.target
     }
 }
 
-// Future work on a renderer for full colour images (should be trivial)
+/// Shrinks (or grows) canvas `bounds` around their centre, a `zoom` above 1 crops in on the middle
+#[cfg(feature = "image")]
+fn zoom_bounds(bounds: [f64; 2], zoom: f64) -> [f64; 2] {
+    let centre = (bounds[0] + bounds[1]) / 2.0;
+    let half = (bounds[1] - bounds[0]) / 2.0 / zoom;
+    [centre - half, centre + half]
+}
+
+// How the image gets painted, either in its original colours or greyscaled and tinted by the theme
+#[derive(Copy, Clone, Default)]
 enum ColourType {
     Full,
+    #[default]
     Grey,
 }
 
-// What we use for drawing images
-struct ImageShape {
-    image_buffer: DynamicImage,
-    tint_colour: Color,
-    colour_type: ColourType,
-    max: u8,
+impl ColourType {
+    fn toggle(&mut self) {
+        *self = match self {
+            ColourType::Full => ColourType::Grey,
+            ColourType::Grey => ColourType::Full,
+        };
+    }
+}
+
+// An image decoded and ready to draw, decoding is far too slow to do on every frame
+#[cfg(feature = "image")]
+#[derive(Default)]
+struct DecodedImage {
+    pixels: RgbImage,
+    // how bright each pixel looks, in the same order as pixels, so the grey image doesn't work it out every frame
+    luminance: Vec<f64>,
+    // luminance of the brightest pixel, the grey image gets stretched so this comes out as full brightness
+    max: f64,
+}
+
+// How much each channel counts towards perceived brightness (Rec. 709), green looks far brighter than blue
+#[cfg(feature = "image")]
+const LUMINANCE_RED: f64 = 0.2126;
+#[cfg(feature = "image")]
+const LUMINANCE_GREEN: f64 = 0.7152;
+#[cfg(feature = "image")]
+const LUMINANCE_BLUE: f64 = 0.0722;
+
+/// How bright a pixel looks, from 0 to 255
+#[cfg(feature = "image")]
+fn luminance([r, g, b]: [u8; 3]) -> f64 {
+    LUMINANCE_RED * f64::from(r) + LUMINANCE_GREEN * f64::from(g) + LUMINANCE_BLUE * f64::from(b)
 }
 
-impl ImageShape {
-    fn new<const S: usize>(image: &[u8; S], tint_colour: Color, colour_type: ColourType) -> Self {
+#[cfg(feature = "image")]
+impl DecodedImage {
+    fn decode(image: &[u8]) -> Self {
         // Read the image (it'll be a byte array stored in the binary atm) move this over to web_sys assets in the static folder when possible
         // but doing this might make it a paint as you will need to draw the image to an invisible buffer before you are able to get at the pixles
         // atleast from how the documentation looks ughhhhh
@@ -524,50 +2953,646 @@ impl ImageShape {
             .with_guessed_format()
             .unwrap()
             .decode()
-            .unwrap()
-            .flipv(); // we flipv because for whatever reason the image is upside-down
-        let max = img // to make the grey scaled image better we neeed to adjust the image to the max brightness (this gets something close to that)
-            .pixels()
-            .max_by(|x, y| x.2 .0.iter().sum::<u8>().cmp(&y.2 .0.iter().sum::<u8>()))
-            .unwrap()
-            .2
-             .0
-            .iter()
-            .sum::<u8>()
-            / 4; // really we should be doing some luminance shit here
+            .unwrap();
+        Self::from_image(img)
+    }
+
+    fn from_image(img: DynamicImage) -> Self {
+        let pixels = img.flipv().to_rgb8(); // we flipv because for whatever reason the image is upside-down
+        let luminance: Vec<f64> = pixels.pixels().map(|p| luminance(p.0)).collect();
+        // to make the grey scaled image better we need to adjust the image to the max brightness
+        let max = luminance.iter().copied().fold(0.0, f64::max).max(1.0); // a completely black image would divide by zero
+        Self {
+            pixels,
+            luminance,
+            max,
+        }
+    }
+}
+
+// What we use for drawing images
+#[cfg(feature = "image")]
+struct ImageShape<'a> {
+    image: &'a DecodedImage,
+    tint_colour: Color,
+    // what the darkest parts of the grey image fade down to
+    shadow_colour: Color,
+    colour_type: ColourType,
+    invert: bool,
+    // posterise the greyscale down to this many levels, None leaves it smooth
+    levels: Option<u8>,
+}
+
+#[cfg(feature = "image")]
+impl<'a> ImageShape<'a> {
+    fn new(
+        image: &'a DecodedImage,
+        tint_colour: Color,
+        colour_type: ColourType,
+        invert: bool,
+        levels: Option<u8>,
+    ) -> Self {
         Self {
-            image_buffer: img,
+            image,
             tint_colour,
+            shadow_colour: Color::Black,
             colour_type,
-            max,
+            invert,
+            levels,
         }
     }
 }
 
-impl Shape for ImageShape {
+#[cfg(feature = "image")]
+impl ImageShape<'_> {
+    fn with_shadow(mut self, shadow_colour: Color) -> Self {
+        self.shadow_colour = shadow_colour;
+        self
+    }
+}
+
+#[cfg(feature = "image")]
+impl Shape for ImageShape<'_> {
     fn draw(&self, painter: &mut ratzilla::ratatui::widgets::canvas::Painter) {
-        // read the image as luma8 and then start writing each pixle to the canvas
-        let binding = self.image_buffer.to_luma8();
-        let pixles = binding.pixels();
-        let w = binding.width() as usize;
-        pixles.enumerate().for_each(|(i, p)| {
+        match self.colour_type {
+            ColourType::Full => self.draw_full(painter),
+            ColourType::Grey => self.draw_grey(painter),
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl ImageShape<'_> {
+    fn draw_full(&self, painter: &mut ratzilla::ratatui::widgets::canvas::Painter) {
+        // paint each pixle with its own colour
+        let w = self.image.pixels.width() as usize;
+        self.image.pixels.pixels().enumerate().for_each(|(i, p)| {
+            let x = i % w;
+            let y = i / w;
+            if let Some((x, y)) = painter.get_point(x as f64, y as f64) {
+                let [r, g, b] = if self.invert {
+                    p.0.map(|c| u8::MAX - c)
+                } else {
+                    p.0
+                };
+                painter.paint(x, y, Color::Rgb(r, g, b));
+            }
+        });
+    }
+
+    fn draw_grey(&self, painter: &mut ratzilla::ratatui::widgets::canvas::Painter) {
+        // write each pixle's luminance to the canvas
+        let w = self.image.pixels.width() as usize;
+        self.image.luminance.iter().enumerate().for_each(|(i, l)| {
             let x = i % w;
             let y = i / w;
             if let Some((x, y)) = painter.get_point(x as f64, y as f64) {
-                let h = (p.0[0] as f64) / self.max as f64;
-                match self.tint_colour {
-                    Color::Rgb(r, g, b) => painter.paint(
-                        x,
-                        y,
-                        Color::Rgb(
-                            (r as f64 * h) as u8,
-                            (g as f64 * h) as u8,
-                            (b as f64 * h) as u8,
-                        ),
-                    ),
-                    _ => unimplemented!("We currently expect the colour passed to the iamge render to be a Color::Rgb"),
+                let mut h = l / self.image.max;
+                if self.invert {
+                    // clamp first as max is only roughly the brightest pixle, we don't want to go negative
+                    h = 1.0 - h.min(1.0);
+                }
+                if let Some(levels) = self.levels {
+                    h = quantise(h.min(1.0), levels, x, y);
                 }
+                let (r, g, b) = colors::to_rgb(self.tint_colour);
+                let (sr, sg, sb) = colors::to_rgb(self.shadow_colour);
+                let blend = |shadow: u8, tint: u8| {
+                    (f64::from(shadow) + (f64::from(tint) - f64::from(shadow)) * h) as u8
+                };
+                painter.paint(x, y, Color::Rgb(blend(sr, r), blend(sg, g), blend(sb, b)));
             }
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn link_url_matches_each_link() {
+        for (index, link) in LINKS.iter().chain(&EXTRA_LINKS).enumerate() {
+            assert_eq!(link_url(index), Some(link.url));
+        }
+        // the compact row carries on straight after the main list
+        assert_eq!(link_url(LINKS.len()), Some(EXTRA_LINKS[0].url));
+        assert_eq!(link_url(LINKS.len() + EXTRA_LINKS.len()), None);
+    }
+
+    #[test]
+    fn step_selection_wraps_round_both_ends() {
+        assert_eq!(step_selection(Some(2), 3, true, false), Some(0));
+        assert_eq!(step_selection(Some(0), 3, false, false), Some(2));
+        assert_eq!(step_selection(Some(1), 3, true, false), Some(2));
+        assert_eq!(step_selection(Some(1), 3, false, false), Some(0));
+    }
+
+    #[test]
+    fn step_selection_clamps_at_both_ends() {
+        assert_eq!(step_selection(Some(2), 3, true, true), Some(2));
+        assert_eq!(step_selection(Some(0), 3, false, true), Some(0));
+        assert_eq!(step_selection(Some(1), 3, true, true), Some(2));
+    }
+
+    #[test]
+    fn step_selection_starts_from_nothing() {
+        assert_eq!(step_selection(None, 3, true, false), Some(0));
+        assert_eq!(step_selection(None, 3, false, false), Some(2));
+        // a selection left over from a longer list still lands inside this one
+        assert_eq!(step_selection(Some(5), 3, false, false), Some(2));
+    }
+
+    #[test]
+    fn step_selection_on_an_empty_list_selects_nothing() {
+        for (selected, forward, clamp) in [
+            (None, true, false),
+            (None, false, true),
+            (Some(0), true, true),
+            (Some(1), false, false),
+        ] {
+            assert_eq!(step_selection(selected, 0, forward, clamp), None);
+        }
+    }
+
+    // Paints `shape` onto a 2x1 half block canvas, so every cell holds two pixels, the upper one in fg and the lower in bg
+    #[cfg(feature = "image")]
+    fn paint_2x2(shape: &ImageShape) -> Buffer {
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buffer = Buffer::empty(area);
+        Canvas::default()
+            .marker(ratzilla::ratatui::symbols::Marker::HalfBlock)
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| ctx.draw(shape))
+            .render(area, &mut buffer);
+        buffer
+    }
+
+    #[cfg(feature = "image")]
+    fn assert_grey(colour: Color, expected: u8) {
+        let Color::Rgb(r, g, b) = colour else {
+            panic!("expected an rgb colour, got {colour:?}");
+        };
+        for channel in [r, g, b] {
+            assert!(
+                channel.abs_diff(expected) <= 1,
+                "expected grey {expected}, got {colour:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn grey_image_shape_paints_each_pixel_upright() {
+        // top row 0 and 85, bottom row 170 and 255, each one different so we can tell where it lands
+        let greys = [[0, 85], [170, 255]];
+        let image = DecodedImage::from_image(DynamicImage::ImageRgb8(image::RgbImage::from_fn(
+            2,
+            2,
+            |x, y| image::Rgb([greys[y as usize][x as usize]; 3]),
+        )));
+        let shape = ImageShape::new(
+            &image,
+            Color::Rgb(255, 255, 255),
+            ColourType::Grey,
+            false,
+            None,
+        )
+        .with_shadow(Color::Rgb(0, 0, 0));
+        let buffer = paint_2x2(&shape);
+
+        // the flipv in decoding has to be undone by the canvas, so the top row still comes out on top
+        assert_grey(buffer[(0, 0)].fg, 0);
+        assert_grey(buffer[(1, 0)].fg, 85);
+        assert_grey(buffer[(0, 0)].bg, 170);
+        assert_grey(buffer[(1, 0)].bg, 255);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn full_image_shape_keeps_original_colours() {
+        let colours = [[[255, 0, 0], [0, 255, 0]], [[0, 0, 255], [255, 255, 0]]];
+        let image = DecodedImage::from_image(DynamicImage::ImageRgb8(image::RgbImage::from_fn(
+            2,
+            2,
+            |x, y| image::Rgb(colours[y as usize][x as usize]),
+        )));
+        let shape = ImageShape::new(&image, Color::White, ColourType::Full, false, None);
+        let buffer = paint_2x2(&shape);
+
+        assert_eq!(buffer[(0, 0)].fg, Color::Rgb(255, 0, 0));
+        assert_eq!(buffer[(1, 0)].fg, Color::Rgb(0, 255, 0));
+        assert_eq!(buffer[(0, 0)].bg, Color::Rgb(0, 0, 255));
+        assert_eq!(buffer[(1, 0)].bg, Color::Rgb(255, 255, 0));
+    }
+
+    #[test]
+    fn truncate_leaves_text_that_fits() {
+        assert_eq!(truncate_with_ellipsis("Github", 10), "Github");
+        // exactly the right width doesn't need an ellipsis
+        assert_eq!(truncate_with_ellipsis("Github", 6), "Github");
+        assert_eq!(truncate_with_ellipsis("", 0), "");
+    }
+
+    #[test]
+    fn truncate_swaps_the_end_for_an_ellipsis() {
+        // one column over loses two characters, one for the ellipsis
+        assert_eq!(truncate_with_ellipsis("Github", 5), "Gith…");
+        assert_eq!(truncate_with_ellipsis("Github", 1), "…");
+        assert_eq!(truncate_with_ellipsis("Github", 0), "");
+    }
+
+    #[test]
+    fn truncate_drops_wide_characters_whole() {
+        // each of these is two columns, so half of one never gets drawn
+        assert_eq!(truncate_with_ellipsis("日本語", 6), "日本語");
+        assert_eq!(truncate_with_ellipsis("日本語", 5), "日本…");
+        assert_eq!(truncate_with_ellipsis("日本語", 4), "日…");
+        assert_eq!(truncate_with_ellipsis("🦀🦀", 3), "🦀…");
+        assert_eq!(truncate_with_ellipsis("🦀🦀", 2), "…");
+        for max in 0..8 {
+            assert!(display_width(&truncate_with_ellipsis("a日🦀b", max)) <= max);
+        }
+    }
+
+    #[test]
+    fn display_width_counts_wide_characters_twice() {
+        assert_eq!(display_width("Github"), 6);
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("🦀 rust"), 7);
+        assert_eq!(display_width(""), 0);
+    }
+
+    #[test]
+    fn pad_to_width_pads_by_columns_not_chars() {
+        assert_eq!(pad_to_width("ab", 4), "ab  ");
+        assert_eq!(pad_to_width("日本", 6), "日本  ");
+        assert_eq!(display_width(&pad_to_width("🦀", 5)), 5);
+        // already too wide is left alone
+        assert_eq!(pad_to_width("日本語", 4), "日本語");
+    }
+
+    #[test]
+    fn content_is_centred_and_clamped_on_wide_screens() {
+        for (width, x, content_width) in [
+            (80, 0, 80),
+            (160, 0, 160),
+            (161, 0, 160),
+            (200, 20, 160),
+            (401, 120, 160),
+        ] {
+            let area = Rect::new(0, 0, width, 50);
+            let content = centred_rect(area, MAX_CONTENT_WIDTH, area.height);
+            assert_eq!(content, Rect::new(x, 0, content_width, 50), "width {width}");
+        }
+    }
+
+    #[test]
+    fn centred_rect_stays_inside_an_offset_area() {
+        let area = Rect::new(10, 5, 100, 30);
+        assert_eq!(centred_rect(area, 40, 10), Rect::new(40, 15, 40, 10));
+    }
+
+    #[test]
+    fn centred_rect_shrinks_to_a_smaller_area() {
+        let area = Rect::new(3, 4, 10, 6);
+        assert_eq!(
+            centred_rect(area, FIXED_GRID_WIDTH, FIXED_GRID_HEIGHT),
+            area
+        );
+        assert_eq!(centred_rect(Rect::default(), 10, 10), Rect::default());
+    }
+
+    #[test]
+    fn chord_completes_with_its_second_key() {
+        let mut chords = ChordBuffer::default();
+        assert_eq!(chords.press('g', 0.0), ChordPress::Held);
+        assert_eq!(chords.press('g', 100.0), ChordPress::Done(Chord::Top));
+        // and the buffer is empty again afterwards
+        assert_eq!(chords.press('j', 200.0), ChordPress::Plain);
+    }
+
+    #[test]
+    fn chord_broken_by_another_key_hands_back_the_first() {
+        let mut chords = ChordBuffer::default();
+        chords.press('g', 0.0);
+        assert_eq!(chords.press('j', 50.0), ChordPress::Broken('g'));
+        assert_eq!(chords.expire(1000.0), None);
+    }
+
+    #[test]
+    fn chord_key_counts_on_its_own_after_the_timeout() {
+        let mut chords = ChordBuffer::default();
+        chords.press('g', 1000.0);
+        assert_eq!(chords.expire(1000.0 + CHORD_TIMEOUT_MS - 1.0), None);
+        assert_eq!(chords.expire(1000.0 + CHORD_TIMEOUT_MS), Some('g'));
+        // once it's expired the next g starts a fresh chord rather than finishing the old one
+        assert_eq!(chords.expire(5000.0), None);
+        assert_eq!(chords.press('g', 5000.0), ChordPress::Held);
+    }
+
+    #[test]
+    fn keys_that_dont_start_chords_go_straight_through() {
+        let mut chords = ChordBuffer::default();
+        assert_eq!(chords.press('j', 0.0), ChordPress::Plain);
+        assert_eq!(chords.expire(1000.0), None);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn headshot_levels_step_up_then_back_to_smooth() {
+        let mut levels = None;
+        let mut seen = vec![];
+        for _ in 0..=HEADSHOT_LEVELS.len() {
+            levels = next_headshot_levels(levels);
+            seen.push(levels);
+        }
+        assert_eq!(seen, [Some(2), Some(4), Some(8), Some(16), Some(32), None]);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn quantise_snaps_to_the_nearest_level() {
+        for levels in HEADSHOT_LEVELS {
+            let steps = f64::from(levels - 1);
+            for (x, y) in [(0, 0), (1, 2), (3, 3)] {
+                // black and white stay put whatever the dither says
+                assert_eq!(quantise(0.0, levels, x, y), 0.0);
+                assert_eq!(quantise(1.0, levels, x, y), 1.0);
+                for h in [0.1, 0.33, 0.5, 0.9] {
+                    let q = quantise(h, levels, x, y);
+                    assert!((0.0..=1.0).contains(&q));
+                    // always lands on a step, and never more than one step away from where it started
+                    assert_eq!((q * steps).round(), q * steps, "{h} at {levels} levels");
+                    assert!(
+                        (q - h).abs() <= 1.0 / steps,
+                        "{h} at {levels} levels gave {q}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn quantise_to_two_levels_is_black_or_white() {
+        for x in 0..4 {
+            for y in 0..4 {
+                let q = quantise(0.5, 2, x, y);
+                assert!(q == 0.0 || q == 1.0);
+            }
+        }
+        // with fewer than two levels it still treats it as two rather than dividing by zero
+        assert_eq!(quantise(0.9, 1, 1, 0), quantise(0.9, 2, 1, 0));
+    }
+
+    #[test]
+    fn tiny_screens_are_too_small() {
+        assert!(too_small(Rect::new(0, 0, 5, 5)));
+        assert!(too_small(Rect::new(0, 0, 200, MIN_MAIN_HEIGHT - 1)));
+        assert!(too_small(Rect::new(0, 0, MIN_MAIN_WIDTH - 1, 200)));
+        assert!(!too_small(Rect::new(0, 0, MIN_MAIN_WIDTH, MIN_MAIN_HEIGHT)));
+    }
+
+    #[test]
+    fn too_small_message_fills_a_5x5_screen() {
+        let mut terminal =
+            Terminal::new(ratzilla::ratatui::backend::TestBackend::new(5, 5)).unwrap();
+        let theme = ColourTheme::new();
+        terminal
+            .draw(|frame| render_too_small_message(frame, "too small", &theme))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        // wrapped over a few lines, but every word of it is on screen
+        assert!(text.contains("too"), "{text:?}");
+        assert!(text.contains("small"), "{text:?}");
+        assert!(buffer
+            .content()
+            .iter()
+            .all(|cell| cell.bg == theme.color_bg));
+    }
+
+    #[test]
+    fn every_queued_colour_switch_gets_applied() {
+        let (tx, rx) = mpsc::channel();
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buffer = Buffer::empty(area);
+        for _ in 0..3 {
+            let mut effect = dispatch_colour_event(tx.clone(), ColourEvent::Switch);
+            effect.process(Duration::from_millis(FRAME_TICK_MS), &mut buffer, area);
+        }
+
+        let mut theme = ColourTheme::new();
+        assert_eq!(drain_colour_switches(&rx, &mut theme), 3);
+        let mut expected = ColourTheme::new();
+        for _ in 0..3 {
+            expected.switch_colour();
+        }
+        assert_eq!(theme.name, expected.name);
+        assert_eq!(theme.palette(), expected.palette());
+        // and nothing is left for the next frame
+        assert_eq!(drain_colour_switches(&rx, &mut theme), 0);
+    }
+
+    #[test]
+    fn poisoned_lock_still_hands_back_its_state() {
+        let mutex = Arc::new(Mutex::new(ListState::default().with_selected(Some(2))));
+        let poisoner = Arc::clone(&mutex);
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poisoning the lock");
+        })
+        .join();
+        assert!(mutex.is_poisoned());
+
+        // lock_or_recover only adds a console warning on top of this, which needs a browser
+        let (mut state, poisoned) = lock_even_if_poisoned(&mutex);
+        assert!(poisoned);
+        assert_eq!(state.selected(), Some(2));
+        state.select(Some(0));
+        drop(state);
+        assert_eq!(lock_even_if_poisoned(&mutex).0.selected(), Some(0));
+    }
+
+    #[test]
+    fn healthy_lock_is_not_reported_as_poisoned() {
+        let mutex = Mutex::new(ListState::default());
+        assert!(!lock_even_if_poisoned(&mutex).1);
+    }
+
+    // Runs every pane's effect on for `ms`, the same as a frame that draws them all
+    fn tick_main_animations(animations: &mut MainAnimationState, ms: u32) {
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buffer = Buffer::empty(area);
+        for effect in [
+            &mut animations.tabs_effect,
+            &mut animations.title_effect,
+            &mut animations.mini_about_effect,
+            &mut animations.links_effect,
+            &mut animations.about_effect,
+            &mut animations.headshot_effect,
+            &mut animations.help_effect,
+        ] {
+            effect.process(Duration::from_millis(ms), &mut buffer, area);
+        }
+    }
+
+    #[test]
+    fn entrance_ticks_through_to_complete() {
+        let mut animations = MainAnimationState::entrance_over(Transition::default(), 10);
+        assert!(!animations.all_complete());
+        let mut frames = 0;
+        while !animations.all_complete() {
+            tick_main_animations(&mut animations, FRAME_TICK_MS);
+            frames += 1;
+            // a few seconds of frames is plenty, past that it's never going to finish
+            assert!(
+                frames < 1000,
+                "entrance still running after {frames} frames"
+            );
+        }
+        assert!(!animations.any_running());
+    }
+
+    #[test]
+    fn skipping_finishes_the_entrance_in_one_tick() {
+        let mut animations = MainAnimationState::entrance_over(Transition::default(), 10);
+        tick_main_animations(&mut animations, SKIP_TICK_MS);
+        assert!(animations.all_complete());
+    }
+
+    #[test]
+    fn tab_steps_wrap_round_both_ends() {
+        assert!(matches!(Tabs::Main.step(true), Tabs::Blog));
+        assert!(matches!(Tabs::Blog.step(true), Tabs::Main));
+        assert!(matches!(Tabs::Main.step(false), Tabs::Blog));
+        assert!(matches!(Tabs::Blog.step(false), Tabs::Main));
+        for tab in Tabs::ALL {
+            assert_eq!(Tabs::ALL[tab.index()].index(), tab.index());
+        }
+    }
+
+    #[test]
+    fn tab_param_picks_the_starting_tab() {
+        assert!(matches!(Tabs::starting(Some("blog")), Tabs::Blog));
+        assert!(matches!(Tabs::starting(Some("Blog")), Tabs::Blog));
+        assert!(matches!(Tabs::starting(Some("main")), Tabs::Main));
+        assert!(matches!(Tabs::starting(Some("nope")), Tabs::Main));
+        assert!(matches!(Tabs::starting(None), Tabs::Main));
+    }
+
+    // How many frames the draw timing averages over
+    #[cfg(feature = "image")]
+    const TIMED_DRAWS: u32 = 10;
+
+    // Not a benchmark as such, just rough numbers (run with --nocapture to see them) showing why the decode happens once
+    #[cfg(feature = "image")]
+    #[test]
+    fn headshot_draw_is_far_cheaper_than_decoding() {
+        let start = std::time::Instant::now();
+        let image = DecodedImage::decode(HEADSHOT);
+        let decode = start.elapsed();
+
+        // about the size the headshot pane is on a laptop screen
+        let area = Rect::new(0, 0, 60, 40);
+        let mut buffer = Buffer::empty(area);
+        for (name, colour_type) in [("grey", ColourType::Grey), ("full", ColourType::Full)] {
+            let shape = ImageShape::new(&image, Color::White, colour_type, false, Some(4));
+            let start = std::time::Instant::now();
+            for _ in 0..TIMED_DRAWS {
+                Canvas::default()
+                    .marker(ratzilla::ratatui::symbols::Marker::HalfBlock)
+                    .x_bounds([100.0, 500.0])
+                    .y_bounds([100.0, 750.0])
+                    .paint(|ctx| ctx.draw(&shape))
+                    .render(area, &mut buffer);
+            }
+            let draw = start.elapsed() / TIMED_DRAWS;
+            println!("decode {decode:?}, {name} draw {draw:?} a frame");
+            assert!(
+                draw < decode,
+                "a frame took {draw:?} to draw, longer than the {decode:?} decode"
+            );
+        }
+    }
+
+    #[test]
+    fn tab_switch_waits_for_the_transition_and_keeps_the_last_press() {
+        let mut queue = TabQueue::default();
+        let mut current = Tabs::Main;
+        let mut animations = MainAnimationState::entrance_over(Transition::default(), 10);
+
+        // three presses of Tab while the entrance is still going, each one carries on from the last
+        for _ in 0..3 {
+            let tab = queue.latest(current).step(true);
+            assert!(queue.request(tab, animations.all_complete()).is_none());
+        }
+        assert!(queue.settle(animations.all_complete()).is_none());
+
+        tick_main_animations(&mut animations, SKIP_TICK_MS);
+        // Main, Blog, Main, Blog, so that's where it ends up
+        let settled = queue.settle(animations.all_complete());
+        assert!(matches!(settled, Some(Tabs::Blog)));
+        current = settled.unwrap();
+        assert!(queue.settle(true).is_none());
+
+        // a press and then a press back again mid transition ends up staying put
+        let mut animations = MainAnimationState::entrance_over(Transition::default(), 10);
+        queue.request(queue.latest(current).step(true), animations.all_complete());
+        queue.request(queue.latest(current).step(false), animations.all_complete());
+        tick_main_animations(&mut animations, SKIP_TICK_MS);
+        assert!(matches!(
+            queue.settle(animations.all_complete()),
+            Some(Tabs::Blog)
+        ));
+    }
+
+    #[test]
+    fn tab_switch_goes_straight_through_when_nothing_is_playing() {
+        let mut queue = TabQueue::default();
+        assert!(matches!(queue.request(Tabs::Blog, true), Some(Tabs::Blog)));
+        assert!(queue.settle(true).is_none());
+        // asking again once settled drops anything older that was waiting
+        queue.request(Tabs::Main, false);
+        assert!(matches!(queue.request(Tabs::Blog, true), Some(Tabs::Blog)));
+        assert!(queue.settle(true).is_none());
+    }
+
+    #[test]
+    fn held_chord_key_is_flushed_ahead_of_other_keys() {
+        let mut chords = ChordBuffer::default();
+        assert_eq!(chords.flush(), None);
+        chords.press('g', 0.0);
+        assert_eq!(chords.flush(), Some('g'));
+        // flushed means gone, it doesn't come back round on the timeout or finish a chord later
+        assert_eq!(chords.expire(1000.0), None);
+        assert_eq!(chords.press('g', 10.0), ChordPress::Held);
+    }
+
+    #[test]
+    fn page_scroll_keeps_a_line_of_overlap() {
+        assert_eq!(page_step(20), 19);
+        // always moves somewhere, even in a pane with next to no room
+        assert_eq!(page_step(1), 1);
+        assert_eq!(page_step(0), 1);
+    }
+
+    #[test]
+    fn open_post_titles_the_page() {
+        let post = Post {
+            title: "Making a website out of a terminal",
+            body: "",
+        };
+        assert_eq!(
+            page_meta(Tabs::Blog, Some(&post)).0,
+            "Making a website out of a terminal - Woonters"
+        );
+        assert_eq!(
+            page_meta(Tabs::Blog, None),
+            (Tabs::Blog.meta().0.to_string(), Tabs::Blog.meta().1)
+        );
+        assert_eq!(page_meta(Tabs::Main, None).0, "Woonters");
+    }
+}
@@ -0,0 +1,18 @@
+/// Whether the browser currently matches a css media query, e.g. `matches("(prefers-reduced-motion: reduce)")`
+///
+/// Anything that can't be checked counts as not matching
+pub fn matches(query: &str) -> bool {
+    web_sys::window()
+        .and_then(|w| w.match_media(query).ok()?)
+        .is_some_and(|list| list.matches())
+}
+
+/// Whether the device has a touch screen, which on a phone means there's probably no keyboard either
+pub fn is_touch_device() -> bool {
+    web_sys::window().is_some_and(|w| w.navigator().max_touch_points() > 0)
+}
+
+/// How many real pixels there are to each css pixel, 1 on a normal screen and 2 or more on high dpi ones
+pub fn device_pixel_ratio() -> f64 {
+    web_sys::window().map_or(1.0, |w| w.device_pixel_ratio())
+}
@@ -0,0 +1,73 @@
+use web_sys::Document;
+
+/// Sets the page title along with the OpenGraph tags, so links to the site get a nice preview when shared
+pub fn set_page_meta(title: &str, description: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    document.set_title(title);
+    set_meta_property(&document, "og:title", title);
+    set_meta_property(&document, "og:description", description);
+}
+
+// Update the <meta property=..> tag in the head, making it first if it isn't there yet
+fn set_meta_property(document: &Document, property: &str, content: &str) {
+    let selector = format!("meta[property=\"{property}\"]");
+    let meta = match document.query_selector(&selector) {
+        Ok(Some(meta)) => meta,
+        _ => {
+            let Ok(meta) = document.create_element("meta") else {
+                return;
+            };
+            let _ = meta.set_attribute("property", property);
+            if let Some(head) = document.head() {
+                let _ = head.append_child(&meta);
+            }
+            meta
+        }
+    };
+    let _ = meta.set_attribute("content", content);
+}
+
+/// Puts each colour on the page root as a CSS custom property (`--color_bg` etc.) so the page around the canvas can match it
+pub fn set_css_colours(colours: &[(&str, String)]) {
+    let Some(root) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+    else {
+        return;
+    };
+    let style: String = colours
+        .iter()
+        .map(|(name, hex)| format!("--{name}: {hex};"))
+        .collect();
+    let _ = root.set_attribute("style", &style);
+}
+
+/// Keeps a visually hidden `<p id=..>` with `text` in the page, so screen readers get a description of things only drawn on the canvas
+///
+/// Only touches the DOM when the text actually changes, so calling it again with the same text is cheap
+pub fn set_hidden_text(id: &str, text: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let element = match document.get_element_by_id(id) {
+        Some(element) => element,
+        None => {
+            let (Ok(element), Some(body)) = (document.create_element("p"), document.body()) else {
+                return;
+            };
+            element.set_id(id);
+            // the usual screen reader only trick, in the accessibility tree but taking up no room on screen
+            let _ = element.set_attribute(
+                "style",
+                "position: absolute; width: 1px; height: 1px; overflow: hidden; clip: rect(0 0 0 0); white-space: nowrap;",
+            );
+            let _ = body.append_child(&element);
+            element
+        }
+    };
+    if element.text_content().as_deref() != Some(text) {
+        element.set_text_content(Some(text));
+    }
+}
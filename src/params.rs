@@ -0,0 +1,7 @@
+use web_sys::UrlSearchParams;
+
+/// Reads a query parameter out of the page url, e.g. `get("theme")` for `?theme=daily`
+pub fn get(name: &str) -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    UrlSearchParams::new_with_str(&search).ok()?.get(name)
+}
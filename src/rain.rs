@@ -0,0 +1,89 @@
+use ratzilla::ratatui::{buffer::Buffer, layout::Rect, style::Color};
+use tachyonfx::SimpleRng;
+
+// What the drops are made of
+const GLYPHS: [char; 16] = [
+    'ｱ', 'ｲ', 'ｳ', 'ｴ', 'ｵ', 'ｶ', 'ｷ', 'ｸ', '0', '1', '2', '3', '4', '5', '7', '9',
+];
+
+// How many rows a drop covers, and how fast they fall in rows a second
+const MIN_LENGTH: f32 = 3.0;
+const MAX_LENGTH: f32 = 10.0;
+const MIN_SPEED: f32 = 4.0;
+const MAX_SPEED: f32 = 12.0;
+
+// How far above the top a finished drop can wait before falling again, in screen heights, bigger means sparser rain
+const MAX_WAIT: f32 = 4.0;
+
+// One falling line of characters, each column gets its own
+#[derive(Clone, Copy)]
+struct Drop {
+    // row of the bottom of the drop, negative while it's still waiting above the screen
+    head: f32,
+    length: f32,
+    speed: f32,
+}
+
+impl Drop {
+    fn new(rng: &mut SimpleRng, height: u16) -> Drop {
+        Drop {
+            head: -rng.gen_f32() * MAX_WAIT * f32::from(height),
+            length: MIN_LENGTH + rng.gen_f32() * (MAX_LENGTH - MIN_LENGTH),
+            speed: MIN_SPEED + rng.gen_f32() * (MAX_SPEED - MIN_SPEED),
+        }
+    }
+}
+
+/// Matrix style falling characters for filling otherwise empty bits of the screen
+#[derive(Default)]
+pub struct Rain {
+    area: Rect,
+    drops: Vec<Drop>,
+}
+
+impl Rain {
+    /// Moves every drop on by `tick_ms` and draws them over `area`
+    ///
+    /// Only blank cells get drawn on, so anything already there shows through
+    pub fn render(
+        &mut self,
+        buf: &mut Buffer,
+        area: Rect,
+        colours: (Color, Color),
+        rng: &mut SimpleRng,
+        tick_ms: u32,
+    ) {
+        // new area, new rain, the old drops wouldn't line up with the columns any more
+        if area != self.area {
+            self.area = area;
+            self.drops = (0..area.width)
+                .map(|_| Drop::new(rng, area.height))
+                .collect();
+        }
+        let (head_colour, tail_colour) = colours;
+        for (column, drop) in self.drops.iter_mut().enumerate() {
+            drop.head += drop.speed * tick_ms as f32 / 1000.0;
+            if drop.head - drop.length > f32::from(area.height) {
+                *drop = Drop::new(rng, area.height);
+            }
+            let top = (drop.head - drop.length).max(0.0) as u16;
+            let bottom = (drop.head.max(0.0) as u16).min(area.height);
+            for row in top..bottom {
+                let Some(cell) = buf.cell_mut((area.x + column as u16, area.y + row)) else {
+                    continue;
+                };
+                if cell.symbol() != " " {
+                    continue;
+                }
+                // glyphs stay put as the drop moves over them, it reads as falling rather than flickering
+                let glyph = GLYPHS[(column * 7 + usize::from(row) * 13) % GLYPHS.len()];
+                let colour = if row + 1 == bottom {
+                    head_colour
+                } else {
+                    tail_colour
+                };
+                cell.set_char(glyph).set_fg(colour);
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlCanvasElement, HtmlElement};
+
+/// Saves whatever is on the canvas right now as a png called `filename`
+///
+/// # Errors
+///
+/// Returns an error if the canvas can't be found or the browser won't turn it into a png
+pub fn save_png(filename: &str) -> Result<(), &'static str> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("no document")?;
+    // ratzilla doesn't hand out its canvas, but it's the only one on the page
+    let canvas = document
+        .query_selector("canvas")
+        .ok()
+        .flatten()
+        .and_then(|c| c.dyn_into::<HtmlCanvasElement>().ok())
+        .ok_or("no canvas")?;
+    let url = canvas
+        .to_data_url_with_type("image/png")
+        .map_err(|_| "canvas can't be exported")?;
+    // downloads need a link to click, it never gets added to the page
+    let link = document
+        .create_element("a")
+        .ok()
+        .and_then(|a| a.dyn_into::<HtmlElement>().ok())
+        .ok_or("couldn't make a download link")?;
+    let _ = link.set_attribute("href", &url);
+    let _ = link.set_attribute("download", filename);
+    link.click();
+    Ok(())
+}
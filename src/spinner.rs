@@ -0,0 +1,74 @@
+use ratzilla::ratatui::{style::Stylize, text::Span};
+
+use crate::colors::ColourTheme;
+
+/// A little "working on it" animation, which frame shows is worked out from the clock so nothing has to step it along
+pub struct Spinner {
+    pub frames: &'static [&'static str],
+    // how long each frame stays up
+    pub frame_ms: f64,
+}
+
+/// The default braille spinner, used by `spinner`
+pub const BRAILLE: Spinner = Spinner {
+    frames: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+    frame_ms: 80.0,
+};
+
+// Shown instead of the spinner when motion is turned down
+const STILL: &str = "…";
+
+impl Spinner {
+    /// The frame to show at `frame_clock` milliseconds
+    pub fn frame(&self, frame_clock: f64) -> &'static str {
+        let index = (frame_clock.max(0.0) / self.frame_ms) as usize % self.frames.len();
+        self.frames[index]
+    }
+
+    /// The frame for `frame_clock` in the theme's accent colour
+    pub fn span(&self, frame_clock: f64, theme: &ColourTheme) -> Span<'static> {
+        Span::raw(self.frame(frame_clock)).fg(theme.color_fg_alt)
+    }
+}
+
+/// The default spinner at `frame_clock` milliseconds, in the theme's accent colour
+pub fn spinner(frame_clock: f64, theme: &ColourTheme) -> Span<'static> {
+    BRAILLE.span(frame_clock, theme)
+}
+
+/// A busy indicator that doesn't move, for when motion is turned down
+pub fn still(theme: &ColourTheme) -> Span<'static> {
+    Span::raw(STILL).fg(theme.color_fg_alt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_only_depends_on_the_clock() {
+        for clock in [0.0, 79.9, 80.0, 1234.5] {
+            assert_eq!(BRAILLE.frame(clock), BRAILLE.frame(clock));
+        }
+        assert_eq!(BRAILLE.frame(0.0), "⠋");
+        assert_eq!(BRAILLE.frame(79.9), "⠋");
+        assert_eq!(BRAILLE.frame(80.0), "⠙");
+        // a full lap later it's back where it started
+        let lap = BRAILLE.frame_ms * BRAILLE.frames.len() as f64;
+        assert_eq!(BRAILLE.frame(lap + 85.0), BRAILLE.frame(85.0));
+    }
+
+    #[test]
+    fn frame_before_the_clock_starts_is_the_first() {
+        assert_eq!(BRAILLE.frame(-500.0), BRAILLE.frames[0]);
+    }
+
+    #[test]
+    fn span_uses_the_accent_colour() {
+        let theme = ColourTheme::new();
+        let span = spinner(160.0, &theme);
+        assert_eq!(span.content, "⠹");
+        assert_eq!(span.style.fg, Some(theme.color_fg_alt));
+        assert_eq!(still(&theme).content, STILL);
+    }
+}
@@ -0,0 +1,18 @@
+use web_sys::Storage;
+
+// localStorage isn't always there (private browsing etc.) so everything in here quietly does nothing without it
+fn local_storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Reads `key` out of localStorage
+pub fn get(key: &str) -> Option<String> {
+    local_storage()?.get_item(key).ok()?
+}
+
+/// Writes `value` to `key` in localStorage, this is best effort so failures are ignored
+pub fn set(key: &str, value: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(key, value);
+    }
+}
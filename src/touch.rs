@@ -0,0 +1,33 @@
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::MouseEvent;
+
+// How big each cell is on the canvas and how far in the grid starts, these match what ratzilla's canvas backend draws with
+const CELL_WIDTH: f64 = 10.0;
+const CELL_HEIGHT: f64 = 19.0;
+const CANVAS_PADDING: f64 = 5.0;
+
+/// Calls `callback` with the (column, row) of the cell under every tap or click on the page
+///
+/// Taps that land outside the canvas are ignored
+pub fn on_tap(mut callback: impl FnMut(u16, u16) + 'static) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let closure = Closure::<dyn FnMut(_)>::new(move |event: MouseEvent| {
+        let Some(canvas) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.query_selector("canvas").ok()?)
+        else {
+            return;
+        };
+        let bounds = canvas.get_bounding_client_rect();
+        let x = (f64::from(event.client_x()) - bounds.left() - CANVAS_PADDING) / CELL_WIDTH;
+        let y = (f64::from(event.client_y()) - bounds.top() - CANVAS_PADDING) / CELL_HEIGHT;
+        if x >= 0.0 && y >= 0.0 {
+            callback(x as u16, y as u16);
+        }
+    });
+    let _ = document.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+    // the listener lives as long as the page does
+    closure.forget();
+}
@@ -0,0 +1,26 @@
+use std::{cell::Cell, rc::Rc};
+
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::js_sys;
+
+/// Calls `callback` with how many milliseconds the page was away for, every time it comes back from being a background tab
+pub fn on_return(mut callback: impl FnMut(f64) + 'static) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    // when the page last went out of view, None while it's showing
+    let hidden_at = Rc::new(Cell::new(None::<f64>));
+    let listener_document = document.clone();
+    let closure = Closure::<dyn FnMut()>::new(move || {
+        let now = js_sys::Date::now();
+        if listener_document.hidden() {
+            hidden_at.set(Some(now));
+        } else if let Some(hidden_at) = hidden_at.take() {
+            callback((now - hidden_at).max(0.0));
+        }
+    });
+    let _ = document
+        .add_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref());
+    // the listener lives as long as the page does
+    closure.forget();
+}